@@ -37,9 +37,27 @@ pub const ONE_DAY_SLOTS: u64 = 24 * ONE_HOUR_SLOTS;
 /// The number of slots in one week.
 pub const ONE_WEEK_SLOTS: u64 = 7 * ONE_DAY_SLOTS;
 
+/// The maximum number of squares on a board.
+pub const MAX_SQUARES: usize = 64;
+
+/// The default number of squares on a board.
+pub const DEFAULT_SQUARES: u64 = 25;
+
+/// The number of squares in legacy miner and round accounts.
+pub const LEGACY_SQUARES: usize = 25;
+
+/// The default number of slots a round is open for deploys.
+pub const ROUND_SLOTS: u64 = ONE_MINUTE_SLOTS;
+
 /// The number of slots for breather between rounds.
 pub const INTERMISSION_SLOTS: u64 = 35;
 
+/// The default number of slots after a round ends before its rewards expire.
+pub const EXPIRY_SLOTS: u64 = ONE_DAY_SLOTS;
+
+/// The number of slots after a round ends before it can be aborted if entropy was never revealed.
+pub const ABORT_SLOTS: u64 = ONE_HOUR_SLOTS;
+
 /// The maximum token supply (5 million).
 pub const MAX_SUPPLY: u64 = ONE_ORE * 5_000_000;
 
@@ -73,18 +91,31 @@ pub const TREASURY: &[u8] = b"treasury";
 /// The seed of the LP pool account PDA.
 pub const LP_POOL: &[u8] = b"lp_pool";
 
+/// The seed of the liquid staking receipt mint PDA.
+pub const RECEIPT: &[u8] = b"receipt";
+
 /// The address of the sol mint account.
 pub const SOL_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
 
 /// The address to indicate ORE rewards are split between all miners.
 pub const SPLIT_ADDRESS: Pubkey = pubkey!("SpLiT11111111111111111111111111111111111112");
 
+/// The current layout version of automation accounts.
+pub const AUTOMATION_VERSION: u64 = 2;
+
 /// The address to indicate automation is permissionless.
 pub const EXECUTOR_ADDRESS: Pubkey = pubkey!("executor11111111111111111111111111111111112");
 
 /// Denominator for fee calculations.
 pub const DENOMINATOR_BPS: u64 = 10_000;
 
+/// Stake lock durations (in seconds) and the reward weight multiplier (in bps) each one grants.
+pub const STAKE_LOCKS: [(i64, u64); 3] = [
+    (30 * ONE_DAY, 12_500),
+    (90 * ONE_DAY, 15_000),
+    (365 * ONE_DAY, 20_000),
+];
+
 /// The address of the boost reserve token account.
 pub const BOOST_RESERVE_TOKEN: Pubkey = pubkey!("Gce36ZUsBDJsoLrfCBxUB5Sfq2DsGunofStvxFx6rBiD");
 
@@ -108,3 +139,32 @@ pub const VAR_ADDRESS: Pubkey = pubkey!("BWCaDY96Xe4WkFq1M7UiCCRcChsJ3p51L5KrGzh
 
 /// The address which can call the bury and wrap instructions.
 pub const BURY_AUTHORITY: Pubkey = pubkey!("HNWhK5f8RMWBqcA7mXJPaxdTPGrha3rrqUrri7HSKb3T");
+
+/// Pause flag for deploys, automation and round resets.
+pub const PAUSE_MINING: u64 = 1 << 0;
+
+/// Pause flag for checkpoints, miner claims and closing expired rounds and settled miners. Round
+/// expiry is not extended while paused.
+pub const PAUSE_CLAIMS: u64 = 1 << 1;
+
+/// Pause flag for stake deposits and compounding.
+pub const PAUSE_STAKING: u64 = 1 << 2;
+
+/// Pause flag for stake withdrawals and yield claims.
+pub const PAUSE_WITHDRAWALS: u64 = 1 << 3;
+
+/// Pause flag for treasury operations (buyback, bury, wrap, liq).
+pub const PAUSE_TREASURY: u64 = 1 << 4;
+
+/// All valid pause flags.
+pub const PAUSE_ALL: u64 =
+    PAUSE_MINING | PAUSE_CLAIMS | PAUSE_STAKING | PAUSE_WITHDRAWALS | PAUSE_TREASURY;
+
+/// Randomness is read from the finalized entropy program var.
+pub const RANDOMNESS_ENTROPY: u64 = 0;
+
+/// Randomness is read from the SlotHashes sysvar entry at the round end slot.
+///
+/// For test clusters only. The leader of the round end slot can grind its hash to pick the winning
+/// square, so this must not be used for rounds with real value at stake.
+pub const RANDOMNESS_SLOT_HASHES: u64 = 1;
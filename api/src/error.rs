@@ -1,6 +1,6 @@
 use steel::*;
 
-#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, IntoPrimitive)]
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
 #[repr(u32)]
 pub enum OreError {
     #[error("Amount too small")]
@@ -20,6 +20,117 @@ pub enum OreError {
 
     #[error("LP pool already initialized")]
     LpPoolAlreadyInitialized = 5,
+
+    #[error("Basis points exceed denominator")]
+    InvalidBasisPoints = 6,
+
+    #[error("Max supply is below current supply")]
+    MaxSupplyBelowSupply = 7,
+
+    #[error("No pending admin proposal")]
+    NoPendingAdmin = 8,
+
+    #[error("Instruction is paused")]
+    Paused = 9,
+
+    #[error("Invalid pause flags")]
+    InvalidPauseFlags = 10,
+
+    #[error("Invalid top miner")]
+    InvalidTopMiner = 11,
+
+    #[error("Invalid round timing")]
+    InvalidRoundTiming = 12,
+
+    #[error("Invalid grid size")]
+    InvalidGridSize = 13,
+
+    #[error("Miner has not checkpointed the prior round")]
+    NotCheckpointed = 14,
+
+    #[error("Round is not active")]
+    RoundNotActive = 15,
+
+    #[error("Round has not ended")]
+    RoundNotEnded = 16,
+
+    #[error("Round has not expired")]
+    RoundNotExpired = 17,
+
+    #[error("Treasury lamports changed during swap")]
+    SwapChangedTreasury = 18,
+
+    #[error("Mint supply changed during swap")]
+    SwapChangedSupply = 19,
+
+    #[error("Swap did not complete")]
+    SwapIncomplete = 20,
+
+    #[error("Transfer did not complete")]
+    TransferIncomplete = 21,
+
+    #[error("Insufficient treasury balance")]
+    InsufficientTreasuryBalance = 22,
+
+    #[error("Insufficient miner balance for rent and rewards")]
+    InsufficientMinerBalance = 23,
+
+    #[error("Miner deployed more than the round")]
+    InvalidRoundDeployed = 24,
+
+    #[error("Round totals are inconsistent")]
+    InvalidRoundTotals = 25,
+
+    #[error("Stake token balance is below stake balance")]
+    StakeBalanceMismatch = 26,
+
+    #[error("Accumulated rewards is negative")]
+    NegativeRewards = 27,
+
+    #[error("Invalid automation strategy")]
+    InvalidStrategy = 28,
+
+    #[error("Round cannot be aborted")]
+    RoundNotAbortable = 29,
+
+    #[error("Invalid randomness source")]
+    InvalidRandomness = 30,
+
+    #[error("Invalid stake lock duration")]
+    InvalidLockDuration = 31,
+
+    #[error("Stake lock cannot be shortened")]
+    LockShortened = 32,
+
+    #[error("Stake is locked")]
+    StakeLocked = 33,
+
+    #[error("Withdrawals must be requested and completed after the cooldown")]
+    WithdrawCooldownRequired = 34,
+
+    #[error("Withdrawal cooldown has not elapsed")]
+    WithdrawNotReady = 35,
+
+    #[error("No pending withdrawal")]
+    NoPendingWithdraw = 36,
+
+    #[error("Invalid withdrawal cooldown")]
+    InvalidWithdrawCooldown = 37,
+
+    #[error("Stake cannot be transferred to this authority")]
+    InvalidStakeTransfer = 38,
+
+    #[error("Stake has a pending withdrawal")]
+    PendingWithdraw = 39,
+
+    #[error("Miner has unclaimed rewards or an open checkpoint")]
+    MinerNotClosable = 40,
+
+    #[error("Stake lock has not expired")]
+    LockNotExpired = 41,
+
+    #[error("Insufficient automation balance")]
+    InsufficientAutomationBalance = 42,
 }
 
 error!(OreError);
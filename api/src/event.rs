@@ -1,11 +1,40 @@
 use serde::{Deserialize, Serialize};
+use serde_big_array::BigArray;
 use steel::*;
 
+use crate::{consts::MAX_SQUARES, state::Config};
+
 pub enum OreEvent {
     Reset = 0,
     Bury = 1,
     Deploy = 2,
     Liq = 3,
+    Config = 4,
+    DeployWeighted = 5,
+    Checkpoint = 6,
+    Claim = 7,
+    Stake = 8,
+    Reload = 9,
+}
+
+/// The asset claimed in a [`ClaimEvent`].
+pub enum ClaimKind {
+    Sol = 0,
+    Ore = 1,
+    Yield = 2,
+}
+
+/// The staking action recorded in a [`StakeEvent`].
+pub enum StakeKind {
+    Deposit = 0,
+    Withdraw = 1,
+    Compound = 2,
+    Lock = 3,
+    RequestWithdraw = 4,
+    Transfer = 5,
+    DepositLiquid = 6,
+    WithdrawLiquid = 7,
+    Unlock = 8,
 }
 
 #[repr(C)]
@@ -47,6 +76,9 @@ pub struct ResetEvent {
     /// The total amount of ORE minted for the round.
     pub total_minted: u64,
 
+    /// The source of randomness used to settle the round (see `RANDOMNESS_*` consts).
+    pub randomness: u64,
+
     /// The timestamp of the event.
     pub ts: i64,
 }
@@ -104,6 +136,38 @@ pub struct DeployEvent {
     pub ts: i64,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct DeployWeightedEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the deployer.
+    pub authority: Pubkey,
+
+    /// The amount of SOL deployed to each square.
+    #[serde(with = "BigArray")]
+    pub amounts: [u64; MAX_SQUARES],
+
+    /// The mask of the squares deployed to.
+    pub mask: u64,
+
+    /// The round id.
+    pub round_id: u64,
+
+    /// The signer of the deployer.
+    pub signer: Pubkey,
+
+    /// The total amount of SOL deployed.
+    pub total_amount: u64,
+
+    /// The total number of squares deployed to.
+    pub total_squares: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
 pub struct LiqEvent {
@@ -120,7 +184,187 @@ pub struct LiqEvent {
     pub ts: i64,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct CheckpointEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The round that was checkpointed.
+    pub round_id: u64,
+
+    /// The amount of SOL credited to the miner.
+    pub rewards_sol: u64,
+
+    /// The amount of ORE credited to the miner.
+    pub rewards_ore: u64,
+
+    /// The fee paid to the signer for checkpointing.
+    pub bot_fee: u64,
+
+    /// The signer of the checkpoint.
+    pub signer: Pubkey,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ClaimEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority that claimed.
+    pub authority: Pubkey,
+
+    /// The asset that was claimed (see `ClaimKind`).
+    pub kind: u64,
+
+    /// The amount claimed.
+    pub amount: u64,
+
+    /// The account that received the claim.
+    pub recipient: Pubkey,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct StakeEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the stake account, or the depositor for liquid staking actions.
+    pub authority: Pubkey,
+
+    /// The staking action (see `StakeKind`).
+    pub kind: u64,
+
+    /// The amount of ORE deposited, withdrawn, compounded, locked, requested for withdrawal or
+    /// transferred.
+    pub amount: u64,
+
+    /// The stake account balance after the action.
+    pub balance: u64,
+
+    /// The total stake weight in the treasury after the action.
+    pub total_staked: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ReloadEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The amount of SOL moved from the miner into the automation.
+    pub amount: u64,
+
+    /// The automation balance after the reload.
+    pub automation_balance: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ConfigEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The admin that updated the config.
+    pub admin: Pubkey,
+
+    /// The total amount of ORE minted per round.
+    pub reward_per_round: u64,
+
+    /// The max supply for the ORE token.
+    pub max_supply: u64,
+
+    /// Basis points of the round reward allocated to the motherlode.
+    pub motherlode_bps: u64,
+
+    /// Basis points of buried ORE shared with stakers.
+    pub stake_bps: u64,
+
+    /// The address that receives admin fees.
+    pub fee_collector: Pubkey,
+
+    /// Basis points of deployed SOL charged as an admin fee.
+    pub fee_rate: u64,
+
+    /// Bitmask of paused instruction groups.
+    pub paused: u64,
+
+    /// The number of slots a round is open for deploys.
+    pub round_slots: u64,
+
+    /// The number of slots between the end of a round and when it can be reset.
+    pub intermission_slots: u64,
+
+    /// The number of slots after a round ends before its unclaimed rewards expire.
+    pub expiry_slots: u64,
+
+    /// The number of squares on the board.
+    pub squares: u64,
+
+    /// The number of slots after a round ends before it can be aborted and refunded.
+    pub abort_slots: u64,
+
+    /// The source of randomness used to settle rounds.
+    pub randomness: u64,
+
+    /// The number of seconds a requested stake withdrawal waits before it can be completed.
+    pub withdraw_cooldown: i64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+impl ConfigEvent {
+    pub fn new(config: &Config, ts: i64) -> Self {
+        Self {
+            disc: OreEvent::Config as u64,
+            admin: config.admin,
+            reward_per_round: config.reward_per_round,
+            max_supply: config.max_supply,
+            motherlode_bps: config.motherlode_bps,
+            stake_bps: config.stake_bps,
+            fee_collector: config.fee_collector,
+            fee_rate: config.fee_rate,
+            paused: config.paused,
+            round_slots: config.round_slots,
+            intermission_slots: config.intermission_slots,
+            expiry_slots: config.expiry_slots,
+            squares: config.squares,
+            abort_slots: config.abort_slots,
+            randomness: config.randomness,
+            withdraw_cooldown: config.withdraw_cooldown,
+            ts,
+        }
+    }
+}
+
 event!(ResetEvent);
 event!(BuryEvent);
 event!(DeployEvent);
+event!(DeployWeightedEvent);
 event!(LiqEvent);
+event!(ConfigEvent);
+event!(CheckpointEvent);
+event!(ClaimEvent);
+event!(StakeEvent);
+event!(ReloadEvent);
@@ -1,33 +1,61 @@
 use steel::*;
 
+use crate::consts::MAX_SQUARES;
+
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive)]
 pub enum OreInstruction {
     // Miner
     Automate = 0,
+    SetAutomationLimits = 40,
     Checkpoint = 2,
+    CheckpointMany = 39,
     ClaimSOL = 3,
     ClaimORE = 4,
     Close = 5,
     Deploy = 6,
+    DeployWeighted = 7,
+    DeployWide = 44,
     Log = 8,
     Reset = 9,
     ReloadSOL = 21,
+    Abort = 28,
+    CloseMiner = 36,
+    SetPayout = 37,
+    Harvest = 38,
+    MigrateMiner = 42,
+    MigrateRound = 43,
 
     // Staker
     Deposit = 10,
     Withdraw = 11,
     ClaimYield = 12,
     CompoundYield = 22,
+    LockStake = 29,
+    RequestWithdraw = 30,
+    CompleteWithdraw = 31,
+    TransferStake = 32,
+    DepositLiquid = 34,
+    WithdrawLiquid = 35,
+    ExpireLock = 45,
 
     // Admin
+    Initialize = 1,
     Buyback = 13,
     Bury = 24,
     Wrap = 14,
-    SetAdmin = 15,
+    ProposeAdmin = 15,
+    AcceptAdmin = 20,
+    CancelAdminProposal = 23,
+    SetConfig = 16,
+    SetFeeCollector = 17,
+    SetFeeRate = 18,
     NewVar = 19,
     Liq = 25,
     InitializeLpPool = 26,
+    SetPause = 27,
+    InitializeReceipt = 33,
+    MigrateConfig = 41,
 }
 
 #[repr(C)]
@@ -41,6 +69,15 @@ pub struct Automate {
     pub reload: [u8; 8],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetAutomationLimits {
+    pub max_rounds: [u8; 8],
+    pub max_spend: [u8; 8],
+    pub expires_at: [u8; 8],
+    pub stop_on_win: [u8; 8],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct ClaimSOL {}
@@ -56,6 +93,111 @@ pub struct Deploy {
     pub squares: [u8; 4],
 }
 
+/// A deploy with a 64-bit square mask, for boards with more than 32 squares.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct DeployWide {
+    pub amount: [u8; 8],
+    pub squares: [u8; 8],
+}
+
+/// Header for a weighted deploy. Followed by `len` [`SquareAmount`] entries.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct DeployWeighted {
+    pub len: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SquareAmount {
+    pub square: u8,
+    pub amount: [u8; 8],
+}
+
+impl DeployWeighted {
+    /// Parses the header and the trailing square amounts from instruction data.
+    pub fn try_from_bytes_with_entries(
+        data: &[u8],
+    ) -> Result<(&Self, &[SquareAmount]), ProgramError> {
+        let header_len = std::mem::size_of::<Self>();
+        if data.len() < header_len {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let (header, entries) = data.split_at(header_len);
+        let header = Self::try_from_bytes(header)?;
+        let entries = bytemuck::try_cast_slice::<u8, SquareAmount>(entries)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        let len = u64::from_le_bytes(header.len) as usize;
+        if entries.len() != len || len > MAX_SQUARES {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok((header, entries))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct LockStake {
+    pub duration: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct RequestWithdraw {
+    pub amount: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CompleteWithdraw {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct TransferStake {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct DepositLiquid {
+    pub amount: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct WithdrawLiquid {
+    pub shares: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct InitializeReceipt {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ExpireLock {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct MigrateConfig {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct MigrateMiner {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct MigrateRound {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Initialize {
+    pub reward_per_round: [u8; 8],
+    pub max_supply: [u8; 8],
+    pub motherlode_bps: [u8; 8],
+    pub stake_bps: [u8; 8],
+    pub squares: [u8; 8],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Log {}
@@ -64,6 +206,10 @@ pub struct Log {}
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Reset {}
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Abort {}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Mine {
@@ -87,10 +233,33 @@ pub struct Uncommit {
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetAdmin {
+pub struct ProposeAdmin {
     pub admin: [u8; 32],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct AcceptAdmin {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CancelAdminProposal {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetConfig {
+    pub reward_per_round: [u8; 8],
+    pub max_supply: [u8; 8],
+    pub motherlode_bps: [u8; 8],
+    pub stake_bps: [u8; 8],
+    pub round_slots: [u8; 8],
+    pub intermission_slots: [u8; 8],
+    pub expiry_slots: [u8; 8],
+    pub abort_slots: [u8; 8],
+    pub randomness: [u8; 8],
+    pub withdraw_cooldown: [u8; 8],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct SetFeeCollector {
@@ -103,6 +272,12 @@ pub struct SetFeeRate {
     pub fee_rate: [u8; 8],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetPause {
+    pub paused: [u8; 8],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Wrap {
@@ -146,10 +321,30 @@ pub struct ClaimYield {
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Checkpoint {}
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CheckpointMany {}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Close {}
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CloseMiner {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Harvest {
+    pub restake_bps: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetPayout {
+    pub payout: [u8; 32],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct NewVar {
@@ -188,22 +383,48 @@ pub struct InitializeLpPool {
 }
 
 instruction!(OreInstruction, Automate);
+instruction!(OreInstruction, SetAutomationLimits);
 instruction!(OreInstruction, Checkpoint);
+instruction!(OreInstruction, CheckpointMany);
 instruction!(OreInstruction, ClaimSOL);
 instruction!(OreInstruction, ClaimORE);
 instruction!(OreInstruction, Deploy);
+instruction!(OreInstruction, DeployWeighted);
+instruction!(OreInstruction, DeployWide);
 instruction!(OreInstruction, Log);
 instruction!(OreInstruction, Close);
 instruction!(OreInstruction, Reset);
 instruction!(OreInstruction, ReloadSOL);
+instruction!(OreInstruction, Abort);
+instruction!(OreInstruction, CloseMiner);
+instruction!(OreInstruction, SetPayout);
+instruction!(OreInstruction, Harvest);
+instruction!(OreInstruction, MigrateMiner);
+instruction!(OreInstruction, MigrateRound);
 instruction!(OreInstruction, Deposit);
 instruction!(OreInstruction, Withdraw);
 instruction!(OreInstruction, ClaimYield);
 instruction!(OreInstruction, CompoundYield);
+instruction!(OreInstruction, LockStake);
+instruction!(OreInstruction, RequestWithdraw);
+instruction!(OreInstruction, CompleteWithdraw);
+instruction!(OreInstruction, TransferStake);
+instruction!(OreInstruction, DepositLiquid);
+instruction!(OreInstruction, WithdrawLiquid);
+instruction!(OreInstruction, ExpireLock);
+instruction!(OreInstruction, Initialize);
 instruction!(OreInstruction, Buyback);
 instruction!(OreInstruction, Bury);
 instruction!(OreInstruction, Wrap);
-instruction!(OreInstruction, SetAdmin);
+instruction!(OreInstruction, ProposeAdmin);
+instruction!(OreInstruction, AcceptAdmin);
+instruction!(OreInstruction, CancelAdminProposal);
+instruction!(OreInstruction, SetConfig);
+instruction!(OreInstruction, SetFeeCollector);
+instruction!(OreInstruction, SetFeeRate);
+instruction!(OreInstruction, SetPause);
 instruction!(OreInstruction, NewVar);
 instruction!(OreInstruction, Liq);
 instruction!(OreInstruction, InitializeLpPool);
+instruction!(OreInstruction, InitializeReceipt);
+instruction!(OreInstruction, MigrateConfig);
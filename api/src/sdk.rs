@@ -1,9 +1,17 @@
+//! Instruction builders for the ORE program.
+//!
+//! Instructions that emit events take the board PDA and the ORE program after their other
+//! accounts, followed by any optional accounts. `ClaimSOL`, `ClaimORE`, `ReloadSOL`, `Deposit`,
+//! `Withdraw`, `ClaimYield` and `CompoundYield` did not take these accounts before, and
+//! `Checkpoint` did not take the ORE program, so transactions built against older builders fail
+//! with `NotEnoughAccountKeys`. See the README for the full list of breaking changes.
+
 use solana_program::pubkey::Pubkey;
 use spl_associated_token_account::get_associated_token_address;
 use steel::*;
 
 use crate::{
-    consts::{BOARD, SOL_MINT},
+    consts::{BOARD, MAX_SQUARES, SOL_MINT},
     instruction::*,
     state::*,
 };
@@ -47,12 +55,64 @@ impl OreSdk {
         )
     }
 
-    pub fn claim_sol(&self, signer: Pubkey) -> Instruction {
-        claim_sol(self.mint, signer)
+    pub fn set_automation_limits(
+        &self,
+        signer: Pubkey,
+        max_rounds: u64,
+        max_spend: u64,
+        expires_at: u64,
+        stop_on_win: bool,
+    ) -> Instruction {
+        set_automation_limits(
+            self.mint,
+            signer,
+            max_rounds,
+            max_spend,
+            expires_at,
+            stop_on_win,
+        )
+    }
+
+    pub fn claim_sol(&self, signer: Pubkey, payout: Pubkey) -> Instruction {
+        claim_sol(self.mint, signer, payout)
+    }
+
+    pub fn claim_ore(&self, signer: Pubkey, payout: Pubkey) -> Instruction {
+        claim_ore(self.mint, signer, payout)
+    }
+
+    pub fn set_payout(&self, signer: Pubkey, payout: Pubkey) -> Instruction {
+        set_payout(self.mint, signer, payout)
+    }
+
+    pub fn harvest(
+        &self,
+        signer: Pubkey,
+        payout: Pubkey,
+        round_id: u64,
+        restake_bps: u64,
+    ) -> Instruction {
+        harvest(self.mint, signer, payout, round_id, restake_bps)
     }
 
-    pub fn claim_ore(&self, signer: Pubkey) -> Instruction {
-        claim_ore(self.mint, signer)
+    pub fn initialize(
+        &self,
+        signer: Pubkey,
+        reward_per_round: u64,
+        max_supply: u64,
+        motherlode_bps: u64,
+        stake_bps: u64,
+        squares: u64,
+    ) -> Instruction {
+        initialize(
+            self.mint,
+            signer,
+            reward_per_round,
+            max_supply,
+            motherlode_bps,
+            stake_bps,
+            squares,
+        )
     }
 
     pub fn deploy(
@@ -61,11 +121,21 @@ impl OreSdk {
         authority: Pubkey,
         amount: u64,
         round_id: u64,
-        squares: [bool; 25],
+        squares: &[bool],
     ) -> Instruction {
         deploy(self.mint, signer, authority, amount, round_id, squares)
     }
 
+    pub fn deploy_weighted(
+        &self,
+        signer: Pubkey,
+        authority: Pubkey,
+        round_id: u64,
+        amounts: &[(u8, u64)],
+    ) -> Instruction {
+        deploy_weighted(self.mint, signer, authority, round_id, amounts)
+    }
+
     pub fn buyback(
         &self,
         signer: Pubkey,
@@ -97,16 +167,91 @@ impl OreSdk {
         reset(self.mint, signer, fee_collector, round_id, top_miner)
     }
 
+    pub fn abort(&self, signer: Pubkey, round_id: u64) -> Instruction {
+        abort(self.mint, signer, round_id)
+    }
+
     pub fn close(&self, signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
         close(self.mint, signer, round_id, rent_payer)
     }
 
+    pub fn close_miner(&self, signer: Pubkey) -> Instruction {
+        close_miner(self.mint, signer)
+    }
+
+    pub fn migrate_miner(&self, signer: Pubkey, authority: Pubkey) -> Instruction {
+        migrate_miner(self.mint, signer, authority)
+    }
+
+    pub fn migrate_round(&self, signer: Pubkey, round_id: u64) -> Instruction {
+        migrate_round(self.mint, signer, round_id)
+    }
+
     pub fn checkpoint(&self, signer: Pubkey, authority: Pubkey, round_id: u64) -> Instruction {
         checkpoint(self.mint, signer, authority, round_id)
     }
 
-    pub fn set_admin(&self, signer: Pubkey, admin: Pubkey) -> Instruction {
-        set_admin(self.mint, signer, admin)
+    pub fn checkpoint_many(
+        &self,
+        signer: Pubkey,
+        authorities: &[Pubkey],
+        round_id: u64,
+    ) -> Instruction {
+        checkpoint_many(self.mint, signer, authorities, round_id)
+    }
+
+    pub fn propose_admin(&self, signer: Pubkey, admin: Pubkey) -> Instruction {
+        propose_admin(self.mint, signer, admin)
+    }
+
+    pub fn accept_admin(&self, signer: Pubkey) -> Instruction {
+        accept_admin(self.mint, signer)
+    }
+
+    pub fn cancel_admin_proposal(&self, signer: Pubkey) -> Instruction {
+        cancel_admin_proposal(self.mint, signer)
+    }
+
+    pub fn set_config(
+        &self,
+        signer: Pubkey,
+        reward_per_round: u64,
+        max_supply: u64,
+        motherlode_bps: u64,
+        stake_bps: u64,
+        round_slots: u64,
+        intermission_slots: u64,
+        expiry_slots: u64,
+        abort_slots: u64,
+        randomness: u64,
+        withdraw_cooldown: i64,
+    ) -> Instruction {
+        set_config(
+            self.mint,
+            signer,
+            reward_per_round,
+            max_supply,
+            motherlode_bps,
+            stake_bps,
+            round_slots,
+            intermission_slots,
+            expiry_slots,
+            abort_slots,
+            randomness,
+            withdraw_cooldown,
+        )
+    }
+
+    pub fn set_fee_collector(&self, signer: Pubkey, fee_collector: Pubkey) -> Instruction {
+        set_fee_collector(self.mint, signer, fee_collector)
+    }
+
+    pub fn set_fee_rate(&self, signer: Pubkey, fee_rate: u64) -> Instruction {
+        set_fee_rate(self.mint, signer, fee_rate)
+    }
+
+    pub fn set_pause(&self, signer: Pubkey, paused: u64) -> Instruction {
+        set_pause(self.mint, signer, paused)
     }
 
     pub fn deposit(
@@ -131,8 +276,44 @@ impl OreSdk {
         claim_yield(self.mint, signer, amount)
     }
 
-    pub fn compound_yield(&self, signer: Pubkey) -> Instruction {
-        compound_yield(self.mint, signer)
+    pub fn compound_yield(&self, signer: Pubkey, authority: Pubkey) -> Instruction {
+        compound_yield(self.mint, signer, authority)
+    }
+
+    pub fn lock_stake(&self, signer: Pubkey, duration: i64) -> Instruction {
+        lock_stake(self.mint, signer, duration)
+    }
+
+    pub fn expire_lock(&self, signer: Pubkey, authority: Pubkey) -> Instruction {
+        expire_lock(self.mint, signer, authority)
+    }
+
+    pub fn request_withdraw(&self, signer: Pubkey, amount: u64) -> Instruction {
+        request_withdraw(self.mint, signer, amount)
+    }
+
+    pub fn complete_withdraw(&self, signer: Pubkey) -> Instruction {
+        complete_withdraw(self.mint, signer)
+    }
+
+    pub fn transfer_stake(&self, signer: Pubkey, new_authority: Pubkey) -> Instruction {
+        transfer_stake(self.mint, signer, new_authority)
+    }
+
+    pub fn initialize_receipt(&self, signer: Pubkey) -> Instruction {
+        initialize_receipt(self.mint, signer)
+    }
+
+    pub fn migrate_config(&self, signer: Pubkey) -> Instruction {
+        migrate_config(self.mint, signer)
+    }
+
+    pub fn deposit_liquid(&self, signer: Pubkey, amount: u64) -> Instruction {
+        deposit_liquid(self.mint, signer, amount)
+    }
+
+    pub fn withdraw_liquid(&self, signer: Pubkey, shares: u64) -> Instruction {
+        withdraw_liquid(self.mint, signer, shares)
     }
 
     pub fn new_var(
@@ -178,7 +359,7 @@ pub fn log(signer: Pubkey, msg: &[u8]) -> Instruction {
     data.extend_from_slice(msg);
     Instruction {
         program_id: crate::ID,
-        accounts: vec![AccountMeta::new(signer, true)],
+        accounts: vec![AccountMeta::new_readonly(signer, true)],
         data: data,
     }
 }
@@ -230,44 +411,197 @@ pub fn automate(
     }
 }
 
-pub fn claim_sol(mint: Pubkey, signer: Pubkey) -> Instruction {
+// let [signer_info, config_info, automation_info, miner_info, system_program] =
+
+pub fn set_automation_limits(
+    mint: Pubkey,
+    signer: Pubkey,
+    max_rounds: u64,
+    max_spend: u64,
+    expires_at: u64,
+    stop_on_win: bool,
+) -> Instruction {
     let config_address = config_pda(mint).0;
+    let automation_address = automation_pda(mint, signer).0;
     let miner_address = miner_pda(mint, signer).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new_readonly(config_address, false),
-            AccountMeta::new(miner_address, false),
+            AccountMeta::new(automation_address, false),
+            AccountMeta::new_readonly(miner_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
+        data: SetAutomationLimits {
+            max_rounds: max_rounds.to_le_bytes(),
+            max_spend: max_spend.to_le_bytes(),
+            expires_at: expires_at.to_le_bytes(),
+            stop_on_win: (stop_on_win as u64).to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, miner_info, system_program, board_info, ore_program, payout_accounts @ ..] =
+
+pub fn claim_sol(mint: Pubkey, signer: Pubkey, payout: Pubkey) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let miner_address = miner_pda(mint, signer).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    if payout != signer {
+        accounts.push(AccountMeta::new(payout, false));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
         data: ClaimSOL {}.to_bytes(),
     }
 }
 
-// let [signer_info, config_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+// let [signer_info, config_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, board_info, ore_program, payout_accounts @ ..] =
 
-pub fn claim_ore(mint: Pubkey, signer: Pubkey) -> Instruction {
+pub fn claim_ore(mint: Pubkey, signer: Pubkey, payout: Pubkey) -> Instruction {
+    let board_address = board_pda(mint).0;
     let config_address = config_pda(mint).0;
     let miner_address = miner_pda(mint, signer).0;
     let treasury_address = treasury_pda(mint).0;
     let treasury_tokens_address = get_associated_token_address(&treasury_address, &mint);
-    let recipient_address = get_associated_token_address(&signer, &mint);
+    let recipient_address = get_associated_token_address(&payout, &mint);
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(recipient_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(treasury_tokens_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    if payout != signer {
+        accounts.push(AccountMeta::new_readonly(payout, false));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ClaimORE {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, board_info, miner_info, round_info, mint_info, payout_info, recipient_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, ore_program] =
+
+pub fn harvest(
+    mint: Pubkey,
+    signer: Pubkey,
+    payout: Pubkey,
+    round_id: u64,
+    restake_bps: u64,
+) -> Instruction {
+    let config_address = config_pda(mint).0;
+    let board_address = board_pda(mint).0;
+    let miner_address = miner_pda(mint, signer).0;
+    let round_address = round_pda(mint, round_id).0;
+    let recipient_address = get_associated_token_address(&payout, &mint);
+    let stake_address = stake_pda(mint, signer).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &mint);
+    let treasury_address = treasury_pda(mint).0;
+    let treasury_tokens_address = get_associated_token_address(&treasury_address, &mint);
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(board_address, false),
             AccountMeta::new(miner_address, false),
+            AccountMeta::new(round_address, false),
             AccountMeta::new(mint, false),
+            AccountMeta::new(payout, false),
             AccountMeta::new(recipient_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new(treasury_tokens_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(spl_token::ID, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
         ],
-        data: ClaimORE {}.to_bytes(),
+        data: Harvest {
+            restake_bps: restake_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, miner_info] =
+
+pub fn set_payout(mint: Pubkey, signer: Pubkey, payout: Pubkey) -> Instruction {
+    let config_address = config_pda(mint).0;
+    let miner_address = miner_pda(mint, signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(miner_address, false),
+        ],
+        data: SetPayout {
+            payout: payout.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, config_info, mint_info, round_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+
+pub fn initialize(
+    mint: Pubkey,
+    signer: Pubkey,
+    reward_per_round: u64,
+    max_supply: u64,
+    motherlode_bps: u64,
+    stake_bps: u64,
+    squares: u64,
+) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let round_address = round_pda(mint, 0).0;
+    let treasury_address = treasury_pda(mint).0;
+    let treasury_tokens_address = treasury_tokens_address(mint);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(round_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: Initialize {
+            reward_per_round: reward_per_round.to_le_bytes(),
+            max_supply: max_supply.to_le_bytes(),
+            motherlode_bps: motherlode_bps.to_le_bytes(),
+            stake_bps: stake_bps.to_le_bytes(),
+            squares: squares.to_le_bytes(),
+        }
+        .to_bytes(),
     }
 }
 
@@ -279,7 +613,7 @@ pub fn deploy(
     authority: Pubkey,
     amount: u64,
     round_id: u64,
-    squares: [bool; 25],
+    squares: &[bool],
 ) -> Instruction {
     let automation_address = automation_pda(mint, authority).0;
     let board_address = board_pda(mint).0;
@@ -288,10 +622,10 @@ pub fn deploy(
     let round_address = round_pda(mint, round_id).0;
     let entropy_var_address = entropy_api::state::var_pda(board_address, 0).0;
 
-    // Convert array of 25 booleans into a 32-bit mask where each bit represents whether
+    // Convert array of booleans into a 64-bit mask where each bit represents whether
     // that square index is selected (1) or not (0)
-    let mut mask: u32 = 0;
-    for (i, &square) in squares.iter().enumerate() {
+    let mut mask: u64 = 0;
+    for (i, &square) in squares.iter().take(MAX_SQUARES).enumerate() {
         if square {
             mask |= 1 << i;
         }
@@ -313,11 +647,68 @@ pub fn deploy(
             AccountMeta::new(entropy_var_address, false),
             AccountMeta::new_readonly(entropy_api::ID, false),
         ],
-        data: Deploy {
+        // Use the original 32-bit encoding unless a square beyond index 31 is selected.
+        data: if mask <= u32::MAX as u64 {
+            Deploy {
+                amount: amount.to_le_bytes(),
+                squares: (mask as u32).to_le_bytes(),
+            }
+            .to_bytes()
+        } else {
+            DeployWide {
+                amount: amount.to_le_bytes(),
+                squares: mask.to_le_bytes(),
+            }
+            .to_bytes()
+        },
+    }
+}
+
+// let [signer_info, authority_info, automation_info, board_info, config_info, miner_info, round_info, system_program, ore_program] =
+
+pub fn deploy_weighted(
+    mint: Pubkey,
+    signer: Pubkey,
+    authority: Pubkey,
+    round_id: u64,
+    amounts: &[(u8, u64)],
+) -> Instruction {
+    let automation_address = automation_pda(mint, authority).0;
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let miner_address = miner_pda(mint, authority).0;
+    let round_address = round_pda(mint, round_id).0;
+    let entropy_var_address = entropy_api::state::var_pda(board_address, 0).0;
+
+    // Append each (square, amount) pair after the instruction header.
+    let mut data = DeployWeighted {
+        len: (amounts.len() as u64).to_le_bytes(),
+    }
+    .to_bytes();
+    for &(square, amount) in amounts {
+        data.extend_from_slice(bytemuck::bytes_of(&SquareAmount {
+            square,
             amount: amount.to_le_bytes(),
-            squares: mask.to_le_bytes(),
-        }
-        .to_bytes(),
+        }));
+    }
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(authority, false),
+            AccountMeta::new(automation_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(round_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+            // Entropy accounts.
+            AccountMeta::new(entropy_var_address, false),
+            AccountMeta::new_readonly(entropy_api::ID, false),
+        ],
+        data,
     }
 }
 
@@ -482,6 +873,48 @@ pub fn reset(
     }
 }
 
+// let [signer_info, board_info, config_info, round_info, round_next_info, var_info, system_program, ore_program] =
+
+pub fn abort(mint: Pubkey, signer: Pubkey, round_id: u64) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let round_address = round_pda(mint, round_id).0;
+    let round_next_address = round_pda(mint, round_id + 1).0;
+    let entropy_var_address = entropy_api::state::var_pda(board_address, 0).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(round_address, false),
+            AccountMeta::new(round_next_address, false),
+            AccountMeta::new_readonly(entropy_var_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: Abort {}.to_bytes(),
+    }
+}
+
+/// Returns the authority of the top miner for a round, given the finalized slot hash and the
+/// miner accounts that participated in it. Returns `None` if the round reward is split or no
+/// matching miner was provided.
+pub fn find_top_miner(round: &Round, slot_hash: [u8; 32], miners: &[Miner]) -> Option<Pubkey> {
+    let mut round = *round;
+    round.slot_hash = slot_hash;
+    let r = round.rng()?;
+    if round.is_split_reward(r) {
+        return None;
+    }
+    let winning_square = round.winning_square(r);
+    let top_miner_sample = round.top_miner_sample(r, winning_square);
+    miners
+        .iter()
+        .find(|m| round.is_top_miner(m, winning_square, top_miner_sample))
+        .map(|m| m.authority)
+}
+
 // let [signer_info, board_info, rent_payer_info, round_info, treasury_info, system_program] =
 
 pub fn close(mint: Pubkey, signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
@@ -504,46 +937,266 @@ pub fn close(mint: Pubkey, signer: Pubkey, round_id: u64, rent_payer: Pubkey) ->
     }
 }
 
-// let [signer_info, config_info, board_info, miner_info, round_info, treasury_info, system_program] =
+// let [signer_info, config_info, automation_info, miner_info, treasury_info, system_program] =
 
-pub fn checkpoint(mint: Pubkey, signer: Pubkey, authority: Pubkey, round_id: u64) -> Instruction {
+pub fn close_miner(mint: Pubkey, signer: Pubkey) -> Instruction {
     let config_address = config_pda(mint).0;
-    let miner_address = miner_pda(mint, authority).0;
-    let board_address = board_pda(mint).0;
-    let round_address = round_pda(mint, round_id).0;
+    let automation_address = automation_pda(mint, signer).0;
+    let miner_address = miner_pda(mint, signer).0;
     let treasury_address = treasury_pda(mint).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new_readonly(config_address, false),
-            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(automation_address, false),
             AccountMeta::new(miner_address, false),
-            AccountMeta::new(round_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
-        data: Checkpoint {}.to_bytes(),
+        data: CloseMiner {}.to_bytes(),
     }
 }
 
-pub fn set_admin(mint: Pubkey, signer: Pubkey, admin: Pubkey) -> Instruction {
+// let [signer_info, config_info, miner_info, system_program] =
+
+pub fn migrate_miner(mint: Pubkey, signer: Pubkey, authority: Pubkey) -> Instruction {
     let config_address = config_pda(mint).0;
+    let miner_address = miner_pda(mint, authority).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: MigrateMiner {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, round_info, system_program] =
+
+pub fn migrate_round(mint: Pubkey, signer: Pubkey, round_id: u64) -> Instruction {
+    let config_address = config_pda(mint).0;
+    let round_address = round_pda(mint, round_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(round_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: MigrateRound {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, board_info, miner_info, round_info, treasury_info, system_program, ore_program] =
+
+pub fn checkpoint(mint: Pubkey, signer: Pubkey, authority: Pubkey, round_id: u64) -> Instruction {
+    let config_address = config_pda(mint).0;
+    let miner_address = miner_pda(mint, authority).0;
+    let board_address = board_pda(mint).0;
+    let round_address = round_pda(mint, round_id).0;
+    let treasury_address = treasury_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(round_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: Checkpoint {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, board_info, round_info, treasury_info, system_program, ore_program, miner_infos @ ..] =
+
+pub fn checkpoint_many(
+    mint: Pubkey,
+    signer: Pubkey,
+    authorities: &[Pubkey],
+    round_id: u64,
+) -> Instruction {
+    let config_address = config_pda(mint).0;
+    let board_address = board_pda(mint).0;
+    let round_address = round_pda(mint, round_id).0;
+    let treasury_address = treasury_pda(mint).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new_readonly(board_address, false),
+        AccountMeta::new(round_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    for authority in authorities {
+        accounts.push(AccountMeta::new(miner_pda(mint, *authority).0, false));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: CheckpointMany {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, system_program] =
+
+pub fn propose_admin(mint: Pubkey, signer: Pubkey, admin: Pubkey) -> Instruction {
+    let config_address = config_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
-        data: SetAdmin {
+        data: ProposeAdmin {
             admin: admin.to_bytes(),
         }
         .to_bytes(),
     }
 }
 
-// let [signer_info, payer_info, config_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+// let [signer_info, config_info] =
+
+pub fn accept_admin(mint: Pubkey, signer: Pubkey) -> Instruction {
+    let config_address = config_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: AcceptAdmin {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info] =
+
+pub fn cancel_admin_proposal(mint: Pubkey, signer: Pubkey) -> Instruction {
+    let config_address = config_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: CancelAdminProposal {}.to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, config_info, mint_info, ore_program] =
+
+pub fn set_config(
+    mint: Pubkey,
+    signer: Pubkey,
+    reward_per_round: u64,
+    max_supply: u64,
+    motherlode_bps: u64,
+    stake_bps: u64,
+    round_slots: u64,
+    intermission_slots: u64,
+    expiry_slots: u64,
+    abort_slots: u64,
+    randomness: u64,
+    withdraw_cooldown: i64,
+) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: SetConfig {
+            reward_per_round: reward_per_round.to_le_bytes(),
+            max_supply: max_supply.to_le_bytes(),
+            motherlode_bps: motherlode_bps.to_le_bytes(),
+            stake_bps: stake_bps.to_le_bytes(),
+            round_slots: round_slots.to_le_bytes(),
+            intermission_slots: intermission_slots.to_le_bytes(),
+            expiry_slots: expiry_slots.to_le_bytes(),
+            abort_slots: abort_slots.to_le_bytes(),
+            randomness: randomness.to_le_bytes(),
+            withdraw_cooldown: withdraw_cooldown.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, config_info, ore_program] =
+
+pub fn set_fee_collector(mint: Pubkey, signer: Pubkey, fee_collector: Pubkey) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: SetFeeCollector {
+            fee_collector: fee_collector.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, config_info, ore_program] =
+
+pub fn set_fee_rate(mint: Pubkey, signer: Pubkey, fee_rate: u64) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: SetFeeRate {
+            fee_rate: fee_rate.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, config_info, ore_program] =
+
+pub fn set_pause(mint: Pubkey, signer: Pubkey, paused: u64) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: SetPause {
+            paused: paused.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, payer_info, config_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program, board_info, ore_program] =
 
 pub fn deposit(
     mint: Pubkey,
@@ -552,6 +1205,7 @@ pub fn deposit(
     amount: u64,
     compound_fee: u64,
 ) -> Instruction {
+    let board_address = board_pda(mint).0;
     let config_address = config_pda(mint).0;
     let stake_address = stake_pda(mint, signer).0;
     let stake_tokens_address = get_associated_token_address(&stake_address, &mint);
@@ -571,6 +1225,8 @@ pub fn deposit(
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(spl_token::ID, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
         ],
         data: Deposit {
             amount: amount.to_le_bytes(),
@@ -580,9 +1236,10 @@ pub fn deposit(
     }
 }
 
-// let [signer_info, config_info, mint_info, recipient_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+// let [signer_info, config_info, mint_info, recipient_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program, board_info, ore_program] =
 
 pub fn withdraw(mint: Pubkey, signer: Pubkey, amount: u64) -> Instruction {
+    let board_address = board_pda(mint).0;
     let config_address = config_pda(mint).0;
     let stake_address = stake_pda(mint, signer).0;
     let stake_tokens_address = get_associated_token_address(&stake_address, &mint);
@@ -601,6 +1258,8 @@ pub fn withdraw(mint: Pubkey, signer: Pubkey, amount: u64) -> Instruction {
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(spl_token::ID, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
         ],
         data: Withdraw {
             amount: amount.to_le_bytes(),
@@ -609,9 +1268,212 @@ pub fn withdraw(mint: Pubkey, signer: Pubkey, amount: u64) -> Instruction {
     }
 }
 
-// let [signer_info, config_info, automation_info, miner_info, system_program] = accounts else {
+// let [signer_info, config_info, stake_info, treasury_info, board_info, ore_program] =
+
+pub fn request_withdraw(mint: Pubkey, signer: Pubkey, amount: u64) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let stake_address = stake_pda(mint, signer).0;
+    let treasury_address = treasury_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: RequestWithdraw {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, mint_info, recipient_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program, board_info, ore_program] =
+
+pub fn complete_withdraw(mint: Pubkey, signer: Pubkey) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let stake_address = stake_pda(mint, signer).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &mint);
+    let recipient_address = get_associated_token_address(&signer, &mint);
+    let treasury_address = treasury_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: CompleteWithdraw {}.to_bytes(),
+    }
+}
+
+// let [signer_info, new_authority_info, config_info, mint_info, stake_info, stake_tokens_info, new_stake_info, new_stake_tokens_info, treasury_info, system_program, token_program, associated_token_program, board_info, ore_program] =
+
+pub fn transfer_stake(mint: Pubkey, signer: Pubkey, new_authority: Pubkey) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let stake_address = stake_pda(mint, signer).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &mint);
+    let new_stake_address = stake_pda(mint, new_authority).0;
+    let new_stake_tokens_address = get_associated_token_address(&new_stake_address, &mint);
+    let treasury_address = treasury_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(new_authority, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(new_stake_address, false),
+            AccountMeta::new(new_stake_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: TransferStake {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, mint_info, receipt_mint_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+
+pub fn initialize_receipt(mint: Pubkey, signer: Pubkey) -> Instruction {
+    let config_address = config_pda(mint).0;
+    let receipt_mint_address = receipt_pda(mint).0;
+    let stake_address = stake_pda(mint, receipt_mint_address).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &mint);
+    let treasury_address = treasury_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(receipt_mint_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: InitializeReceipt {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, system_program] =
+
+pub fn migrate_config(mint: Pubkey, signer: Pubkey) -> Instruction {
+    let config_address = config_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: MigrateConfig {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, mint_info, sender_info, receipt_mint_info, recipient_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, board_info, ore_program] =
+
+pub fn deposit_liquid(mint: Pubkey, signer: Pubkey, amount: u64) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let sender_address = get_associated_token_address(&signer, &mint);
+    let receipt_mint_address = receipt_pda(mint).0;
+    let recipient_address = get_associated_token_address(&signer, &receipt_mint_address);
+    let stake_address = stake_pda(mint, receipt_mint_address).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &mint);
+    let treasury_address = treasury_pda(mint).0;
+    let treasury_tokens_address = treasury_tokens_address(mint);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(sender_address, false),
+            AccountMeta::new(receipt_mint_address, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: DepositLiquid {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, mint_info, recipient_info, receipt_mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, board_info, ore_program] =
+
+pub fn withdraw_liquid(mint: Pubkey, signer: Pubkey, shares: u64) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let recipient_address = get_associated_token_address(&signer, &mint);
+    let receipt_mint_address = receipt_pda(mint).0;
+    let sender_address = get_associated_token_address(&signer, &receipt_mint_address);
+    let stake_address = stake_pda(mint, receipt_mint_address).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &mint);
+    let treasury_address = treasury_pda(mint).0;
+    let treasury_tokens_address = treasury_tokens_address(mint);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(receipt_mint_address, false),
+            AccountMeta::new(sender_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: WithdrawLiquid {
+            shares: shares.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, automation_info, miner_info, system_program, board_info, ore_program] =
 
 pub fn reload_sol(mint: Pubkey, signer: Pubkey, authority: Pubkey) -> Instruction {
+    let board_address = board_pda(mint).0;
     let config_address = config_pda(mint).0;
     let automation_address = automation_pda(mint, authority).0;
     let miner_address = miner_pda(mint, authority).0;
@@ -623,14 +1485,17 @@ pub fn reload_sol(mint: Pubkey, signer: Pubkey, authority: Pubkey) -> Instructio
             AccountMeta::new(automation_address, false),
             AccountMeta::new(miner_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
         ],
         data: ReloadSOL {}.to_bytes(),
     }
 }
 
-// let [signer_info, config_info, mint_info, recipient_info, stake_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+// let [signer_info, config_info, mint_info, recipient_info, stake_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, board_info, ore_program] =
 
 pub fn claim_yield(mint: Pubkey, signer: Pubkey, amount: u64) -> Instruction {
+    let board_address = board_pda(mint).0;
     let config_address = config_pda(mint).0;
     let stake_address = stake_pda(mint, signer).0;
     let recipient_address = get_associated_token_address(&signer, &mint);
@@ -649,6 +1514,8 @@ pub fn claim_yield(mint: Pubkey, signer: Pubkey, amount: u64) -> Instruction {
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(spl_token::ID, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
         ],
         data: ClaimYield {
             amount: amount.to_le_bytes(),
@@ -657,25 +1524,79 @@ pub fn claim_yield(mint: Pubkey, signer: Pubkey, amount: u64) -> Instruction {
     }
 }
 
-pub fn compound_yield(mint: Pubkey, signer: Pubkey) -> Instruction {
+// let [signer_info, config_info, stake_info, treasury_info, board_info, ore_program] =
+
+pub fn lock_stake(mint: Pubkey, signer: Pubkey, duration: i64) -> Instruction {
+    let board_address = board_pda(mint).0;
     let config_address = config_pda(mint).0;
     let stake_address = stake_pda(mint, signer).0;
-    let stake_tokens_address = get_associated_token_address(&stake_address, &mint);
     let treasury_address = treasury_pda(mint).0;
-    let treasury_tokens_address = treasury_tokens_address(mint);
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new_readonly(config_address, false),
-            AccountMeta::new(mint, false),
             AccountMeta::new(stake_address, false),
-            AccountMeta::new(stake_tokens_address, false),
             AccountMeta::new(treasury_address, false),
-            AccountMeta::new(treasury_tokens_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: LockStake {
+            duration: duration.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, stake_info, treasury_info, board_info, ore_program] =
+
+pub fn expire_lock(mint: Pubkey, signer: Pubkey, authority: Pubkey) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let stake_address = stake_pda(mint, authority).0;
+    let treasury_address = treasury_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
         ],
+        data: ExpireLock {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, mint_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, board_info, ore_program, authority_accounts @ ..] =
+
+pub fn compound_yield(mint: Pubkey, signer: Pubkey, authority: Pubkey) -> Instruction {
+    let board_address = board_pda(mint).0;
+    let config_address = config_pda(mint).0;
+    let stake_address = stake_pda(mint, authority).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &mint);
+    let treasury_address = treasury_pda(mint).0;
+    let treasury_tokens_address = treasury_tokens_address(mint);
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(stake_address, false),
+        AccountMeta::new(stake_tokens_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(treasury_tokens_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    if authority != signer {
+        accounts.push(AccountMeta::new_readonly(authority, false));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
         data: CompoundYield {}.to_bytes(),
     }
 }
@@ -710,3 +1631,27 @@ pub fn new_var(
         .to_bytes(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize() {
+        let mint = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let ix = initialize(mint, signer, 1, 2, 3, 4, 5);
+        assert_eq!(ix.accounts.len(), 10);
+        assert_eq!(ix.accounts[0], AccountMeta::new(signer, true));
+        assert_eq!(ix.accounts[1].pubkey, board_pda(mint).0);
+        assert_eq!(ix.accounts[2].pubkey, config_pda(mint).0);
+        assert_eq!(ix.accounts[4].pubkey, round_pda(mint, 0).0);
+        assert_eq!(ix.data[0], OreInstruction::Initialize as u8);
+        let args = Initialize::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(u64::from_le_bytes(args.reward_per_round), 1);
+        assert_eq!(u64::from_le_bytes(args.max_supply), 2);
+        assert_eq!(u64::from_le_bytes(args.motherlode_bps), 3);
+        assert_eq!(u64::from_le_bytes(args.stake_bps), 4);
+        assert_eq!(u64::from_le_bytes(args.squares), 5);
+    }
+}
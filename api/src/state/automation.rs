@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::automation_pda;
+use crate::{
+    error::OreError,
+    state::{automation_pda, Miner},
+};
 
 use super::OreAccount;
 
@@ -27,11 +30,50 @@ pub struct Automation {
     pub strategy: u64,
 
     /// The mask of squares this automation should deploy to if preferred strategy.
-    /// If strategy is Random, first bit is used to determine how many squares to deploy to.
+    /// If strategy is Random, LeastCrowded or MostCrowded, first byte is used to determine how many squares to deploy to.
     pub mask: u64,
 
     /// Whether or not to auto-reload SOL winnings into the automation balance.
     pub reload: u64,
+
+    /// The layout version of this automation account.
+    pub version: u64,
+
+    /// The maximum number of rounds to play, or zero for no limit.
+    pub max_rounds: u64,
+
+    /// The number of rounds played since limits were last set.
+    pub rounds_played: u64,
+
+    /// The maximum amount of SOL to deploy, or zero for no limit.
+    pub max_spend: u64,
+
+    /// The amount of SOL deployed since limits were last set.
+    pub total_spent: u64,
+
+    /// The slot after which this automation stops, or zero for no expiry.
+    pub expires_at: u64,
+
+    /// Whether or not to stop after the miner wins a round.
+    pub stop_on_win: u64,
+
+    /// The miner's lifetime wins at the last deploy, used to detect a win.
+    pub last_wins: u64,
+
+    /// The total number of rounds this automation has played.
+    pub lifetime_rounds: u64,
+
+    /// The total amount of SOL this automation has deployed.
+    pub lifetime_deployed: u64,
+
+    /// The total amount of SOL this automation has paid to executors in fees.
+    pub lifetime_fees: u64,
+
+    /// The total amount of SOL reloaded into this automation from mining rewards.
+    pub lifetime_reloaded: u64,
+
+    /// The ID of the round this automation last deployed in.
+    pub last_round_id: u64,
 }
 
 #[repr(u8)]
@@ -40,11 +82,16 @@ pub enum AutomationStrategy {
     Random = 0,
     Preferred = 1,
     Discretionary = 2,
+    LeastCrowded = 3,
+    MostCrowded = 4,
 }
 
 impl AutomationStrategy {
-    pub fn from_u64(value: u64) -> Self {
-        Self::try_from(value as u8).unwrap()
+    pub fn from_u64(value: u64) -> Result<Self, ProgramError> {
+        u8::try_from(value)
+            .ok()
+            .and_then(|value| Self::try_from(value).ok())
+            .ok_or(OreError::InvalidStrategy.into())
     }
 }
 
@@ -52,6 +99,65 @@ impl Automation {
     pub fn pda(&self, mint: Pubkey) -> (Pubkey, u8) {
         automation_pda(mint, self.authority)
     }
+
+    /// Returns true if any of the automation's limits have been reached.
+    pub fn is_limit_reached(&self, clock: &Clock, miner: &Miner) -> bool {
+        (self.max_rounds > 0 && self.rounds_played >= self.max_rounds)
+            || (self.max_spend > 0 && self.total_spent + self.amount > self.max_spend)
+            || (self.expires_at > 0 && clock.slot >= self.expires_at)
+            || (self.stop_on_win > 0 && miner.lifetime_wins > self.last_wins)
+    }
 }
 
 account!(OreAccount, Automation);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_limit_reached() {
+        let mut automation = Automation::zeroed();
+        let mut miner = Miner::zeroed();
+        let mut clock = Clock {
+            slot: u64::MAX,
+            ..Default::default()
+        };
+        assert!(!automation.is_limit_reached(&clock, &miner));
+
+        // Round limit.
+        clock.slot = 0;
+        automation.max_rounds = 3;
+        automation.rounds_played = 2;
+        assert!(!automation.is_limit_reached(&clock, &miner));
+        automation.rounds_played = 3;
+        assert!(automation.is_limit_reached(&clock, &miner));
+        automation.max_rounds = 0;
+
+        // Spend limit, including the next round's deployment.
+        automation.amount = 10;
+        automation.max_spend = 100;
+        automation.total_spent = 90;
+        assert!(!automation.is_limit_reached(&clock, &miner));
+        automation.total_spent = 91;
+        assert!(automation.is_limit_reached(&clock, &miner));
+        automation.max_spend = 0;
+
+        // Expiry.
+        automation.expires_at = 50;
+        clock.slot = 49;
+        assert!(!automation.is_limit_reached(&clock, &miner));
+        clock.slot = 50;
+        assert!(automation.is_limit_reached(&clock, &miner));
+        automation.expires_at = 0;
+
+        // Stop on win. Refunds increase SOL rewards without counting as a win.
+        automation.stop_on_win = 1;
+        automation.last_wins = 2;
+        miner.lifetime_wins = 2;
+        miner.lifetime_rewards_sol = 1_000;
+        assert!(!automation.is_limit_reached(&clock, &miner));
+        miner.lifetime_wins = 3;
+        assert!(automation.is_limit_reached(&clock, &miner));
+    }
+}
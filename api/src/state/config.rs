@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::{consts::DENOMINATOR_BPS, state::config_pda};
+use crate::{
+    consts::{DENOMINATOR_BPS, TWELVE_HOURS_SLOTS},
+    state::config_pda,
+};
 
 use super::OreAccount;
 
@@ -26,8 +29,42 @@ pub struct Config {
     /// Basis points of buried ORE shared with stakers.
     pub stake_bps: u64,
 
+    /// The address that receives admin fees.
+    pub fee_collector: Pubkey,
+
+    /// Basis points of deployed SOL charged as an admin fee.
+    pub fee_rate: u64,
+
+    /// The proposed admin, awaiting acceptance. Default if there is no pending proposal.
+    pub pending_admin: Pubkey,
+
+    /// Bitmask of paused instruction groups (see `PAUSE_*` consts).
+    pub paused: u64,
+
+    /// The number of slots a round is open for deploys.
+    pub round_slots: u64,
+
+    /// The number of slots between the end of a round and when it can be reset.
+    pub intermission_slots: u64,
+
+    /// The number of slots after a round ends before its unclaimed rewards expire.
+    pub expiry_slots: u64,
+
+    /// The number of squares on the board.
+    pub squares: u64,
+
+    /// The number of slots after a round ends before it can be aborted and refunded.
+    pub abort_slots: u64,
+
+    /// The source of randomness used to settle rounds (see `RANDOMNESS_*` consts).
+    pub randomness: u64,
+
+    /// The number of seconds a requested stake withdrawal waits before it can be completed. Zero
+    /// if withdrawals are immediate.
+    pub withdraw_cooldown: i64,
+
     /// Reserved for future config fields.
-    pub reserved: [u8; 24],
+    pub reserved: [u8; 8],
 }
 
 impl Config {
@@ -41,6 +78,49 @@ impl Config {
         let top_miner_reward = total_reward.saturating_sub(motherlode_reward);
         (top_miner_reward, motherlode_reward)
     }
+
+    pub fn is_paused(&self, flag: u64) -> bool {
+        self.paused & flag != 0
+    }
+
+    /// The number of slots before expiry during which anyone may checkpoint a miner for the bot fee.
+    pub fn bot_fee_slots(&self) -> u64 {
+        TWELVE_HOURS_SLOTS.min(self.expiry_slots / 2)
+    }
+
+    pub fn admin_fee(&self, amount: u64) -> u64 {
+        (amount as u128 * self.fee_rate as u128 / DENOMINATOR_BPS as u128) as u64
+    }
 }
 
 account!(OreAccount, Config);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_admin_fee() {
+        let mut config = Config::zeroed();
+        assert_eq!(config.admin_fee(1_000_000), 0);
+        config.fee_rate = 100;
+        assert_eq!(config.admin_fee(1_000_000), 10_000);
+        assert_eq!(config.admin_fee(99), 0);
+        assert_eq!(config.admin_fee(u64::MAX), u64::MAX / 100);
+    }
+
+    #[test]
+    fn test_split_reward() {
+        let mut config = Config::zeroed();
+        assert_eq!(config.split_reward(1_000), (1_000, 0));
+        config.motherlode_bps = 2_000;
+        assert_eq!(config.split_reward(1_000), (800, 200));
+        config.motherlode_bps = DENOMINATOR_BPS;
+        assert_eq!(config.split_reward(1_000), (0, 1_000));
+
+        // Large rewards saturate instead of overflowing.
+        config.motherlode_bps = 2_000;
+        let (top_miner_reward, motherlode_reward) = config.split_reward(u64::MAX);
+        assert_eq!(top_miner_reward + motherlode_reward, u64::MAX);
+    }
+}
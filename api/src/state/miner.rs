@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
+use serde_big_array::BigArray;
 use steel::*;
 
-use crate::state::{miner_pda, Treasury};
+use crate::{
+    consts::{LEGACY_SQUARES, MAX_SQUARES},
+    error::OreError,
+    state::{miner_pda, Treasury},
+};
 
 use super::OreAccount;
 
@@ -12,10 +17,12 @@ pub struct Miner {
     pub authority: Pubkey,
 
     /// The miner's prospects in the current round.
-    pub deployed: [u64; 25],
+    #[serde(with = "BigArray")]
+    pub deployed: [u64; MAX_SQUARES],
 
     /// The cumulative amount of SOL deployed on each square prior to this miner's move.
-    pub cumulative: [u64; 25],
+    #[serde(with = "BigArray")]
+    pub cumulative: [u64; MAX_SQUARES],
 
     /// SOL witheld in reserve to pay for checkpointing.
     pub checkpoint_fee: u64,
@@ -52,6 +59,12 @@ pub struct Miner {
 
     /// The total amount of SOL this miner has deployed across all rounds.
     pub lifetime_deployed: u64,
+
+    /// The address that receives claimed rewards, or the default pubkey to pay the authority.
+    pub payout: Pubkey,
+
+    /// The total number of rounds this miner has won.
+    pub lifetime_wins: u64,
 }
 
 impl Miner {
@@ -59,8 +72,20 @@ impl Miner {
         miner_pda(mint, self.authority)
     }
 
-    pub fn claim_ore(&mut self, clock: &Clock, treasury: &mut Treasury) -> u64 {
-        self.update_rewards(treasury);
+    pub fn payout_address(&self) -> Pubkey {
+        if self.payout == Pubkey::default() {
+            self.authority
+        } else {
+            self.payout
+        }
+    }
+
+    pub fn claim_ore(
+        &mut self,
+        clock: &Clock,
+        treasury: &mut Treasury,
+    ) -> Result<u64, ProgramError> {
+        self.update_rewards(treasury)?;
         let refined_ore = self.refined_ore;
         let rewards_ore = self.rewards_ore;
         let mut amount = refined_ore + rewards_ore;
@@ -79,7 +104,7 @@ impl Miner {
             self.lifetime_rewards_ore -= fee;
         }
 
-        amount
+        Ok(amount)
     }
 
     pub fn claim_sol(&mut self, clock: &Clock) -> u64 {
@@ -89,12 +114,12 @@ impl Miner {
         amount
     }
 
-    pub fn update_rewards(&mut self, treasury: &Treasury) {
+    pub fn update_rewards(&mut self, treasury: &Treasury) -> Result<(), ProgramError> {
         // Accumulate rewards, weighted by stake balance.
         if treasury.miner_rewards_factor > self.rewards_factor {
             let accumulated_rewards = treasury.miner_rewards_factor - self.rewards_factor;
             if accumulated_rewards < Numeric::ZERO {
-                panic!("Accumulated rewards is negative");
+                return Err(OreError::NegativeRewards.into());
             }
             let personal_rewards = accumulated_rewards * Numeric::from_u64(self.rewards_ore);
             self.refined_ore += personal_rewards.to_u64();
@@ -103,7 +128,60 @@ impl Miner {
 
         // Update this miner account's last seen rewards factor.
         self.rewards_factor = treasury.miner_rewards_factor;
+
+        Ok(())
+    }
+}
+
+/// The miner layout used before boards supported more than 25 squares.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct LegacyMiner {
+    pub authority: Pubkey,
+    pub deployed: [u64; LEGACY_SQUARES],
+    pub cumulative: [u64; LEGACY_SQUARES],
+    pub checkpoint_fee: u64,
+    pub checkpoint_id: u64,
+    pub last_claim_ore_at: i64,
+    pub last_claim_sol_at: i64,
+    pub rewards_factor: Numeric,
+    pub rewards_sol: u64,
+    pub rewards_ore: u64,
+    pub refined_ore: u64,
+    pub round_id: u64,
+    pub lifetime_rewards_sol: u64,
+    pub lifetime_rewards_ore: u64,
+    pub lifetime_deployed: u64,
+}
+
+impl LegacyMiner {
+    /// Converts this account to the current miner layout.
+    pub fn migrate(&self) -> Miner {
+        let mut deployed = [0; MAX_SQUARES];
+        let mut cumulative = [0; MAX_SQUARES];
+        deployed[..LEGACY_SQUARES].copy_from_slice(&self.deployed);
+        cumulative[..LEGACY_SQUARES].copy_from_slice(&self.cumulative);
+        Miner {
+            authority: self.authority,
+            deployed,
+            cumulative,
+            checkpoint_fee: self.checkpoint_fee,
+            checkpoint_id: self.checkpoint_id,
+            last_claim_ore_at: self.last_claim_ore_at,
+            last_claim_sol_at: self.last_claim_sol_at,
+            rewards_factor: self.rewards_factor,
+            rewards_sol: self.rewards_sol,
+            rewards_ore: self.rewards_ore,
+            refined_ore: self.refined_ore,
+            round_id: self.round_id,
+            lifetime_rewards_sol: self.lifetime_rewards_sol,
+            lifetime_rewards_ore: self.lifetime_rewards_ore,
+            lifetime_deployed: self.lifetime_deployed,
+            payout: Pubkey::default(),
+            lifetime_wins: 0,
+        }
     }
 }
 
 account!(OreAccount, Miner);
+account!(OreAccount, LegacyMiner);
@@ -25,12 +25,18 @@ use steel::*;
 pub enum OreAccount {
     Automation = 100,
     Config = 101,
-    Miner = 103,
     Treasury = 104,
     Board = 105,
     Stake = 108,
-    Round = 109,
     LpPool = 110,
+
+    // Version 1 layouts, sized for 25 squares. Migrated with MigrateMiner and MigrateRound.
+    LegacyMiner = 103,
+    LegacyRound = 109,
+
+    // Version 2 layouts, sized for up to MAX_SQUARES squares.
+    Miner = 111,
+    Round = 112,
 }
 
 pub fn automation_pda(mint: Pubkey, authority: Pubkey) -> (Pubkey, u8) {
@@ -74,6 +80,11 @@ pub fn lp_pool_pda(mint: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[LP_POOL, &mint.to_bytes()], &crate::ID)
 }
 
+/// The liquid staking receipt mint. It is also the authority of the stake account backing it.
+pub fn receipt_pda(mint: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RECEIPT, &mint.to_bytes()], &crate::ID)
+}
+
 pub fn treasury_tokens_address(mint: Pubkey) -> Pubkey {
     let treasury_address = treasury_pda(mint).0;
     spl_associated_token_account::get_associated_token_address(&treasury_address, &mint)
@@ -1,7 +1,14 @@
 use serde::{Deserialize, Serialize};
+use serde_big_array::BigArray;
 use steel::*;
 
-use crate::state::round_pda;
+use crate::{
+    consts::{
+        ADMIN_FEE, DEFAULT_SQUARES, DENOMINATOR_BPS, LEGACY_SQUARES, MAX_SQUARES,
+        RANDOMNESS_ENTROPY,
+    },
+    state::{round_pda, Miner},
+};
 
 use super::OreAccount;
 
@@ -12,13 +19,15 @@ pub struct Round {
     pub id: u64,
 
     /// The amount of SOL deployed in each square.
-    pub deployed: [u64; 25],
+    #[serde(with = "BigArray")]
+    pub deployed: [u64; MAX_SQUARES],
 
     /// The hash of the end slot, provided by solana, used for random number generation.
     pub slot_hash: [u8; 32],
 
     /// The count of miners on each square.
-    pub count: [u64; 25],
+    #[serde(with = "BigArray")]
+    pub count: [u64; MAX_SQUARES],
 
     /// The slot at which claims for this round account end.
     pub expires_at: u64,
@@ -46,6 +55,16 @@ pub struct Round {
 
     /// The total amount of SOL won by miners for the round.
     pub total_winnings: u64,
+
+    /// The number of squares on the board for this round.
+    pub squares: u64,
+
+    /// Basis points of deployed SOL charged as an admin fee, snapshotted from the config when the
+    /// round is reset. Migrated legacy rounds use `ADMIN_FEE`, the fixed fee they were created with.
+    pub fee_rate: u64,
+
+    /// The source of randomness used to settle this round, snapshotted at the first deploy.
+    pub randomness: u64,
 }
 
 impl Round {
@@ -65,8 +84,12 @@ impl Round {
         Some(r)
     }
 
+    pub fn admin_fee(&self, amount: u64) -> u64 {
+        (amount as u128 * self.fee_rate as u128 / DENOMINATOR_BPS as u128) as u64
+    }
+
     pub fn winning_square(&self, rng: u64) -> usize {
-        (rng % 25) as usize
+        (rng % self.squares) as usize
     }
 
     pub fn top_miner_sample(&self, rng: u64, winning_square: usize) -> u64 {
@@ -76,6 +99,13 @@ impl Round {
         rng.reverse_bits() % self.deployed[winning_square]
     }
 
+    pub fn is_top_miner(&self, miner: &Miner, winning_square: usize, sample: u64) -> bool {
+        miner.round_id == self.id
+            && miner.deployed[winning_square] > 0
+            && sample >= miner.cumulative[winning_square]
+            && sample < miner.cumulative[winning_square] + miner.deployed[winning_square]
+    }
+
     pub fn calculate_total_winnings(&self, winning_square: usize) -> u64 {
         let mut total_winnings = 0;
         for (i, &deployed) in self.deployed.iter().enumerate() {
@@ -102,7 +132,58 @@ impl Round {
     }
 }
 
+/// The round layout used before boards supported more than 25 squares.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct LegacyRound {
+    pub id: u64,
+    pub deployed: [u64; LEGACY_SQUARES],
+    pub slot_hash: [u8; 32],
+    pub count: [u64; LEGACY_SQUARES],
+    pub expires_at: u64,
+    pub motherlode: u64,
+    pub rent_payer: Pubkey,
+    pub top_miner: Pubkey,
+    pub top_miner_reward: u64,
+    pub total_deployed: u64,
+    pub total_miners: u64,
+    pub total_vaulted: u64,
+    pub total_winnings: u64,
+}
+
+impl LegacyRound {
+    /// Converts this account to the current round layout.
+    ///
+    /// Legacy rounds were created before the fee rate was configurable, so they keep the fixed
+    /// `ADMIN_FEE` rather than the current `Config::fee_rate`.
+    pub fn migrate(&self) -> Round {
+        let mut deployed = [0; MAX_SQUARES];
+        let mut count = [0; MAX_SQUARES];
+        deployed[..LEGACY_SQUARES].copy_from_slice(&self.deployed);
+        count[..LEGACY_SQUARES].copy_from_slice(&self.count);
+        Round {
+            id: self.id,
+            deployed,
+            slot_hash: self.slot_hash,
+            count,
+            expires_at: self.expires_at,
+            motherlode: self.motherlode,
+            rent_payer: self.rent_payer,
+            top_miner: self.top_miner,
+            top_miner_reward: self.top_miner_reward,
+            total_deployed: self.total_deployed,
+            total_miners: self.total_miners,
+            total_vaulted: self.total_vaulted,
+            total_winnings: self.total_winnings,
+            squares: DEFAULT_SQUARES,
+            fee_rate: ADMIN_FEE,
+            randomness: RANDOMNESS_ENTROPY,
+        }
+    }
+}
+
 account!(OreAccount, Round);
+account!(OreAccount, LegacyRound);
 
 #[cfg(test)]
 mod tests {
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::{stake_pda, Treasury};
+use crate::{
+    consts::{DENOMINATOR_BPS, STAKE_LOCKS},
+    error::OreError,
+    state::{stake_pda, Treasury},
+};
 
 use super::OreAccount;
 
@@ -14,17 +18,17 @@ pub struct Stake {
     /// The balance of this stake account.
     pub balance: u64,
 
-    /// Buffer a (placeholder)
-    pub buffer_a: u64,
+    /// The timestamp until which the balance cannot be withdrawn. Zero if unlocked.
+    pub unlock_at: i64,
 
-    /// Buffer b (placeholder)
-    pub buffer_b: u64,
+    /// The reward weight multiplier granted by the current lock, in bps. Zero if unlocked.
+    pub lock_multiplier: u64,
 
-    /// Buffer c (placeholder)
-    pub buffer_c: u64,
+    /// The amount of ORE requested for withdrawal, no longer earning rewards.
+    pub pending_withdraw: u64,
 
-    /// Buffer d (placeholder)
-    pub buffer_d: u64,
+    /// The timestamp at which the pending withdrawal can be completed.
+    pub withdraw_release_at: i64,
 
     /// The lamport reserve to pay fees for auto-compounding bots.
     pub compound_fee_reserve: u64,
@@ -56,12 +60,37 @@ impl Stake {
         stake_pda(mint, self.authority)
     }
 
-    pub fn claim(&mut self, amount: u64, clock: &Clock, treasury: &Treasury) -> u64 {
-        self.update_rewards(treasury);
+    /// Resets this account to an empty stake position for the given authority.
+    pub fn init(&mut self, authority: Pubkey, treasury: &Treasury) {
+        *self = Stake {
+            authority,
+            balance: 0,
+            unlock_at: 0,
+            lock_multiplier: 0,
+            pending_withdraw: 0,
+            withdraw_release_at: 0,
+            compound_fee_reserve: 0,
+            last_claim_at: 0,
+            last_deposit_at: 0,
+            last_withdraw_at: 0,
+            rewards_factor: treasury.stake_rewards_factor,
+            rewards: 0,
+            lifetime_rewards: 0,
+            buffer_f: 0,
+        };
+    }
+
+    pub fn claim(
+        &mut self,
+        amount: u64,
+        clock: &Clock,
+        treasury: &Treasury,
+    ) -> Result<u64, ProgramError> {
+        self.update_rewards(treasury)?;
         let amount = self.rewards.min(amount);
         self.rewards -= amount;
         self.last_claim_at = clock.unix_timestamp;
-        amount
+        Ok(amount)
     }
 
     pub fn deposit(
@@ -70,39 +99,393 @@ impl Stake {
         clock: &Clock,
         treasury: &mut Treasury,
         sender: &TokenAccount,
-    ) -> u64 {
-        self.update_rewards(treasury);
+    ) -> Result<u64, ProgramError> {
+        self.update_rewards(treasury)?;
+        let weight = self.weight();
+        self.expire_lock(clock);
         let amount = sender.amount().min(amount);
         self.balance += amount;
         self.last_deposit_at = clock.unix_timestamp;
-        treasury.total_staked += amount;
-        amount
+        treasury.total_staked = treasury.total_staked - weight + self.weight();
+        Ok(amount)
     }
 
-    pub fn withdraw(&mut self, amount: u64, clock: &Clock, treasury: &mut Treasury) -> u64 {
-        self.update_rewards(treasury);
+    pub fn withdraw(
+        &mut self,
+        amount: u64,
+        clock: &Clock,
+        treasury: &mut Treasury,
+    ) -> Result<u64, ProgramError> {
+        if clock.unix_timestamp < self.unlock_at {
+            return Err(OreError::StakeLocked.into());
+        }
+        self.update_rewards(treasury)?;
+        let weight = self.weight();
+        self.expire_lock(clock);
         let amount = self.balance.min(amount);
         self.balance -= amount;
         self.last_withdraw_at = clock.unix_timestamp;
-        treasury.total_staked -= amount;
-        amount
+        treasury.total_staked = treasury.total_staked - weight + self.weight();
+        Ok(amount)
+    }
+
+    /// Moves balance out of the reward-earning pool into the pending withdrawal. Requesting again
+    /// adds to the pending amount and restarts the cooldown.
+    pub fn request_withdraw(
+        &mut self,
+        amount: u64,
+        cooldown: i64,
+        clock: &Clock,
+        treasury: &mut Treasury,
+    ) -> Result<u64, ProgramError> {
+        if clock.unix_timestamp < self.unlock_at {
+            return Err(OreError::StakeLocked.into());
+        }
+        self.update_rewards(treasury)?;
+        let weight = self.weight();
+        self.expire_lock(clock);
+        let amount = self.balance.min(amount);
+        self.balance -= amount;
+        self.pending_withdraw += amount;
+        self.withdraw_release_at = clock.unix_timestamp + cooldown;
+        treasury.total_staked = treasury.total_staked - weight + self.weight();
+        Ok(amount)
+    }
+
+    /// Releases the pending withdrawal once its cooldown has elapsed.
+    pub fn complete_withdraw(&mut self, clock: &Clock) -> Result<u64, ProgramError> {
+        if self.pending_withdraw == 0 {
+            return Err(OreError::NoPendingWithdraw.into());
+        }
+        if clock.unix_timestamp < self.withdraw_release_at {
+            return Err(OreError::WithdrawNotReady.into());
+        }
+        let amount = self.pending_withdraw;
+        self.pending_withdraw = 0;
+        self.withdraw_release_at = 0;
+        self.last_withdraw_at = clock.unix_timestamp;
+        Ok(amount)
+    }
+
+    /// Locks the stake for the given duration, boosting its reward weight. A lock can be extended
+    /// but never shortened.
+    pub fn lock(
+        &mut self,
+        duration: i64,
+        clock: &Clock,
+        treasury: &mut Treasury,
+    ) -> Result<(), ProgramError> {
+        let Some((_, multiplier)) = STAKE_LOCKS.iter().find(|(d, _)| *d == duration) else {
+            return Err(OreError::InvalidLockDuration.into());
+        };
+        let unlock_at = clock.unix_timestamp + duration;
+        if unlock_at < self.unlock_at {
+            return Err(OreError::LockShortened.into());
+        }
+        self.update_rewards(treasury)?;
+        let weight = self.weight();
+        self.unlock_at = unlock_at;
+        self.lock_multiplier = *multiplier;
+        treasury.total_staked = treasury.total_staked - weight + self.weight();
+        Ok(())
+    }
+
+    /// Drops the boost of an elapsed lock and removes it from the total staked weight.
+    pub fn unlock(&mut self, clock: &Clock, treasury: &mut Treasury) -> Result<(), ProgramError> {
+        if self.unlock_at == 0 || clock.unix_timestamp < self.unlock_at {
+            return Err(OreError::LockNotExpired.into());
+        }
+        self.update_rewards(treasury)?;
+        let weight = self.weight();
+        self.expire_lock(clock);
+        treasury.total_staked = treasury.total_staked - weight + self.weight();
+        Ok(())
+    }
+
+    /// Moves this stake position, including its accrued rewards and lock, into another stake
+    /// account. When merging two locks, the later unlock time and the lower multiplier apply.
+    pub fn transfer(
+        &mut self,
+        to: &mut Stake,
+        clock: &Clock,
+        treasury: &mut Treasury,
+    ) -> Result<u64, ProgramError> {
+        self.update_rewards(treasury)?;
+        to.update_rewards(treasury)?;
+        let weight = self.weight() + to.weight();
+        self.expire_lock(clock);
+        to.expire_lock(clock);
+        let amount = self.balance;
+        let multiplier = if to.balance == 0 {
+            self.multiplier()
+        } else {
+            self.multiplier().min(to.multiplier())
+        };
+        to.unlock_at = to.unlock_at.max(self.unlock_at);
+        to.lock_multiplier = if to.unlock_at == 0 || multiplier == DENOMINATOR_BPS {
+            0
+        } else {
+            multiplier
+        };
+        to.balance += amount;
+        to.rewards += self.rewards;
+        to.lifetime_rewards += self.lifetime_rewards;
+        to.compound_fee_reserve += self.compound_fee_reserve;
+        to.last_deposit_at = clock.unix_timestamp;
+        self.balance = 0;
+        self.rewards = 0;
+        self.lifetime_rewards = 0;
+        self.compound_fee_reserve = 0;
+        self.unlock_at = 0;
+        self.lock_multiplier = 0;
+        self.last_withdraw_at = clock.unix_timestamp;
+        treasury.total_staked = treasury.total_staked - weight + to.weight();
+        Ok(amount)
+    }
+
+    /// The number of receipt tokens to mint for depositing the given amount of ORE into a liquid
+    /// staking pool, given the current receipt supply.
+    pub fn liquid_shares(&self, amount: u64, supply: u64) -> u64 {
+        if supply == 0 || self.balance == 0 {
+            amount
+        } else {
+            (amount as u128 * supply as u128 / self.balance as u128) as u64
+        }
     }
 
-    pub fn update_rewards(&mut self, treasury: &Treasury) {
-        // Accumulate rewards, weighted by stake balance.
+    /// The amount of ORE redeemable for the given number of receipt tokens from a liquid staking
+    /// pool, given the current receipt supply.
+    pub fn liquid_amount(&self, shares: u64, supply: u64) -> u64 {
+        if supply == 0 {
+            0
+        } else {
+            (shares as u128 * self.balance as u128 / supply as u128) as u64
+        }
+    }
+
+    /// The reward weight of this stake account, boosted by its lock multiplier.
+    pub fn weight(&self) -> u64 {
+        (self.balance as u128 * self.multiplier() as u128 / DENOMINATOR_BPS as u128) as u64
+    }
+
+    /// The lock multiplier of this stake account, in bps.
+    fn multiplier(&self) -> u64 {
+        if self.lock_multiplier == 0 {
+            DENOMINATOR_BPS
+        } else {
+            self.lock_multiplier
+        }
+    }
+
+    /// Drops the lock boost once the lock has elapsed.
+    fn expire_lock(&mut self, clock: &Clock) {
+        if self.unlock_at > 0 && clock.unix_timestamp >= self.unlock_at {
+            self.unlock_at = 0;
+            self.lock_multiplier = 0;
+        }
+    }
+
+    pub fn update_rewards(&mut self, treasury: &Treasury) -> Result<(), ProgramError> {
+        // Accumulate rewards, weighted by stake balance and lock multiplier.
         if treasury.stake_rewards_factor > self.rewards_factor {
             let accumulated_rewards = treasury.stake_rewards_factor - self.rewards_factor;
             if accumulated_rewards < Numeric::ZERO {
-                panic!("Accumulated rewards is negative");
+                return Err(OreError::NegativeRewards.into());
             }
-            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.balance);
+            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.weight());
             self.rewards += personal_rewards.to_u64();
             self.lifetime_rewards += personal_rewards.to_u64();
         }
 
         // Update this stake account's last seen rewards factor.
         self.rewards_factor = treasury.stake_rewards_factor;
+
+        Ok(())
     }
 }
 
 account!(OreAccount, Stake);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clock(unix_timestamp: i64) -> Clock {
+        Clock {
+            unix_timestamp,
+            ..Default::default()
+        }
+    }
+
+    fn stake(balance: u64, lock_multiplier: u64, unlock_at: i64) -> Stake {
+        let mut stake = Stake::zeroed();
+        stake.balance = balance;
+        stake.lock_multiplier = lock_multiplier;
+        stake.unlock_at = unlock_at;
+        stake
+    }
+
+    #[test]
+    fn test_weight() {
+        assert_eq!(stake(1_000, 0, 0).weight(), 1_000);
+        assert_eq!(stake(1_000, 15_000, 200).weight(), 1_500);
+    }
+
+    #[test]
+    fn test_lock() {
+        let mut treasury = Treasury::zeroed();
+        let mut stake = stake(1_000, 0, 0);
+        let (duration, multiplier) = STAKE_LOCKS[0];
+        treasury.total_staked = stake.weight();
+        assert_eq!(
+            stake.lock(duration + 1, &clock(100), &mut treasury),
+            Err(OreError::InvalidLockDuration.into())
+        );
+        stake.lock(duration, &clock(100), &mut treasury).unwrap();
+        assert_eq!(stake.unlock_at, 100 + duration);
+        assert_eq!(stake.lock_multiplier, multiplier);
+        assert_eq!(treasury.total_staked, stake.weight());
+        assert_eq!(
+            stake.lock(duration, &clock(50), &mut treasury),
+            Err(OreError::LockShortened.into())
+        );
+
+        // The boost is only dropped once the lock has elapsed.
+        assert_eq!(
+            stake.unlock(&clock(99 + duration), &mut treasury),
+            Err(OreError::LockNotExpired.into())
+        );
+        stake.unlock(&clock(100 + duration), &mut treasury).unwrap();
+        assert_eq!(stake.unlock_at, 0);
+        assert_eq!(stake.lock_multiplier, 0);
+        assert_eq!(treasury.total_staked, 1_000);
+    }
+
+    #[test]
+    fn test_request_withdraw() {
+        let mut treasury = Treasury::zeroed();
+        let mut stake = stake(1_000, 0, 0);
+        treasury.total_staked = stake.weight();
+        let amount = stake
+            .request_withdraw(400, 60, &clock(100), &mut treasury)
+            .unwrap();
+        assert_eq!(amount, 400);
+        assert_eq!(stake.balance, 600);
+        assert_eq!(stake.pending_withdraw, 400);
+        assert_eq!(stake.withdraw_release_at, 160);
+        assert_eq!(treasury.total_staked, 600);
+
+        // Requesting again caps at the balance and restarts the cooldown.
+        let amount = stake
+            .request_withdraw(u64::MAX, 60, &clock(120), &mut treasury)
+            .unwrap();
+        assert_eq!(amount, 600);
+        assert_eq!(stake.balance, 0);
+        assert_eq!(stake.pending_withdraw, 1_000);
+        assert_eq!(stake.withdraw_release_at, 180);
+        assert_eq!(treasury.total_staked, 0);
+
+        // The pending withdrawal is released once the cooldown elapses.
+        assert_eq!(
+            stake.complete_withdraw(&clock(179)),
+            Err(OreError::WithdrawNotReady.into())
+        );
+        assert_eq!(stake.complete_withdraw(&clock(180)), Ok(1_000));
+        assert_eq!(stake.pending_withdraw, 0);
+        assert_eq!(
+            stake.complete_withdraw(&clock(180)),
+            Err(OreError::NoPendingWithdraw.into())
+        );
+    }
+
+    #[test]
+    fn test_request_withdraw_locked() {
+        let mut treasury = Treasury::zeroed();
+        let mut stake = stake(1_000, 15_000, 200);
+        treasury.total_staked = stake.weight();
+        assert_eq!(
+            stake.request_withdraw(400, 60, &clock(199), &mut treasury),
+            Err(OreError::StakeLocked.into())
+        );
+
+        // Once the lock elapses, the boost is dropped from the total staked weight.
+        stake
+            .request_withdraw(400, 60, &clock(200), &mut treasury)
+            .unwrap();
+        assert_eq!(stake.unlock_at, 0);
+        assert_eq!(stake.lock_multiplier, 0);
+        assert_eq!(treasury.total_staked, 600);
+    }
+
+    #[test]
+    fn test_transfer() {
+        let mut treasury = Treasury::zeroed();
+        let mut from = stake(1_000, 15_000, 200);
+        let mut to = Stake::zeroed();
+        from.rewards = 10;
+        treasury.total_staked = from.weight();
+        let amount = from.transfer(&mut to, &clock(100), &mut treasury).unwrap();
+        assert_eq!(amount, 1_000);
+        assert_eq!(to.balance, 1_000);
+        assert_eq!(to.rewards, 10);
+        assert_eq!(to.unlock_at, 200);
+        assert_eq!(to.lock_multiplier, 15_000);
+        assert_eq!(from.balance, 0);
+        assert_eq!(from.rewards, 0);
+        assert_eq!(from.unlock_at, 0);
+        assert_eq!(from.lock_multiplier, 0);
+        assert_eq!(treasury.total_staked, 1_500);
+    }
+
+    #[test]
+    fn test_transfer_merge_locks() {
+        let mut treasury = Treasury::zeroed();
+        let mut from = stake(1_000, 15_000, 200);
+        let mut to = stake(500, 20_000, 300);
+        treasury.total_staked = from.weight() + to.weight();
+        from.transfer(&mut to, &clock(100), &mut treasury).unwrap();
+        assert_eq!(to.balance, 1_500);
+        assert_eq!(to.unlock_at, 300);
+        assert_eq!(to.lock_multiplier, 15_000);
+        assert_eq!(treasury.total_staked, 2_250);
+
+        // Merging into an unlocked position drops the boost but keeps the lock.
+        let mut from = stake(1_000, 12_500, 200);
+        let mut to = stake(1_000, 0, 0);
+        treasury.total_staked = from.weight() + to.weight();
+        from.transfer(&mut to, &clock(100), &mut treasury).unwrap();
+        assert_eq!(to.unlock_at, 200);
+        assert_eq!(to.lock_multiplier, 0);
+        assert_eq!(treasury.total_staked, 2_000);
+    }
+
+    #[test]
+    fn test_liquid_shares() {
+        // An empty pool mints receipt tokens one to one.
+        assert_eq!(stake(0, 0, 0).liquid_shares(500, 0), 500);
+        assert_eq!(stake(0, 0, 0).liquid_shares(500, 1_000), 500);
+        assert_eq!(stake(2_000, 0, 0).liquid_shares(500, 0), 500);
+
+        // Otherwise shares are priced at the pool's exchange rate, rounding down.
+        assert_eq!(stake(2_000, 0, 0).liquid_shares(500, 1_000), 250);
+        assert_eq!(stake(3, 0, 0).liquid_shares(1, 2), 0);
+        assert_eq!(
+            stake(u64::MAX, 0, 0).liquid_shares(u64::MAX, u64::MAX),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn test_liquid_amount() {
+        assert_eq!(stake(2_000, 0, 0).liquid_amount(250, 1_000), 500);
+        assert_eq!(stake(2_000, 0, 0).liquid_amount(1_000, 1_000), 2_000);
+        assert_eq!(stake(3, 0, 0).liquid_amount(1, 2), 1);
+        assert_eq!(stake(2_000, 0, 0).liquid_amount(250, 0), 0);
+
+        // A deposit followed by a withdrawal never returns more than was deposited.
+        let mut pool = stake(3_001, 0, 0);
+        let shares = pool.liquid_shares(1_000, 2_000);
+        pool.balance += 1_000;
+        assert!(pool.liquid_amount(shares, 2_000 + shares) <= 1_000);
+    }
+}
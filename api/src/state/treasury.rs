@@ -29,7 +29,7 @@ pub struct Treasury {
     /// The current total amount of refined ORE mining rewards.
     pub total_refined: u64,
 
-    /// The current total amount of ORE staking deposits.
+    /// The current total reward weight of ORE staking deposits, including lock multipliers.
     pub total_staked: u64,
 
     /// The current total amount of unclaimed ORE mining rewards.
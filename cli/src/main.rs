@@ -1,4 +1,7 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 use entropy_api::prelude::*;
 use jup_swap::{
@@ -13,7 +16,7 @@ use pinocchio_raydium_cpmm_cpi as raydium_cpmm;
 use pinocchio_raydium_locking_program as raydium_locking;
 use solana_account_decoder::UiAccountEncoding;
 use solana_client::{
-    client_error::{reqwest::StatusCode, ClientErrorKind},
+    client_error::{reqwest::StatusCode, ClientError, ClientErrorKind},
     nonblocking::rpc_client::RpcClient,
     rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
     rpc_filter::{Memcmp, RpcFilterType},
@@ -29,7 +32,8 @@ use solana_sdk::{
     signature::{read_keypair_file, Keypair, Signature, Signer},
     system_instruction,
     sysvar,
-    transaction::{Transaction, VersionedTransaction},
+    instruction::InstructionError,
+    transaction::{Transaction, TransactionError, VersionedTransaction},
 };
 use solana_sdk::{keccak, pubkey};
 use spl_associated_token_account::get_associated_token_address;
@@ -57,6 +61,15 @@ async fn main() {
         "claim" => {
             claim(&rpc, &payer).await.unwrap();
         }
+        "set_payout" => {
+            set_payout(&rpc, &payer).await.unwrap();
+        }
+        "set_automation_limits" => {
+            set_automation_limits(&rpc, &payer).await.unwrap();
+        }
+        "harvest" => {
+            harvest(&rpc, &payer).await.unwrap();
+        }
         "board" => {
             log_board(&rpc).await.unwrap();
         }
@@ -69,6 +82,9 @@ async fn main() {
         "reset" => {
             reset(&rpc, &payer).await.unwrap();
         }
+        "abort" => {
+            abort(&rpc, &payer).await.unwrap();
+        }
         "treasury" => {
             log_treasury(&rpc).await.unwrap();
         }
@@ -84,6 +100,33 @@ async fn main() {
         "stake" => {
             log_stake(&rpc, &payer).await.unwrap();
         }
+        "stake_lock" => {
+            stake_lock(&rpc, &payer).await.unwrap();
+        }
+        "request_withdraw" => {
+            request_withdraw(&rpc, &payer).await.unwrap();
+        }
+        "complete_withdraw" => {
+            complete_withdraw(&rpc, &payer).await.unwrap();
+        }
+        "transfer_stake" => {
+            transfer_stake(&rpc, &payer).await.unwrap();
+        }
+        "initialize_receipt" => {
+            initialize_receipt(&rpc, &payer).await.unwrap();
+        }
+        "deposit_liquid" => {
+            deposit_liquid(&rpc, &payer).await.unwrap();
+        }
+        "withdraw_liquid" => {
+            withdraw_liquid(&rpc, &payer).await.unwrap();
+        }
+        "receipt" => {
+            log_receipt(&rpc).await.unwrap();
+        }
+        "deploy_weighted" => {
+            deploy_weighted(&rpc, &payer).await.unwrap();
+        }
         "deploy_all" => {
             deploy_all(&rpc, &payer).await.unwrap();
         }
@@ -93,6 +136,24 @@ async fn main() {
         "set_admin" => {
             set_admin(&rpc, &payer).await.unwrap();
         }
+        "cancel_admin_proposal" => {
+            cancel_admin_proposal(&rpc, &payer).await.unwrap();
+        }
+        "set_config" => {
+            set_config(&rpc, &payer).await.unwrap();
+        }
+        "set_fee_collector" => {
+            set_fee_collector(&rpc, &payer).await.unwrap();
+        }
+        "set_fee_rate" => {
+            set_fee_rate(&rpc, &payer).await.unwrap();
+        }
+        "set_pause" => {
+            set_pause(&rpc, &payer).await.unwrap();
+        }
+        "migrate_config" => {
+            migrate_config(&rpc, &payer).await.unwrap();
+        }
         "ata" => {
             ata(&rpc, &payer).await.unwrap();
         }
@@ -102,9 +163,21 @@ async fn main() {
         "checkpoint_all" => {
             checkpoint_all(&rpc, &payer).await.unwrap();
         }
+        "expire_locks" => {
+            expire_locks(&rpc, &payer).await.unwrap();
+        }
+        "compound_all" => {
+            compound_all(&rpc, &payer).await.unwrap();
+        }
         "close_all" => {
             close_all(&rpc, &payer).await.unwrap();
         }
+        "close_miners" => {
+            close_miners(&rpc, &payer).await.unwrap();
+        }
+        "migrate_accounts" => {
+            migrate_accounts(&rpc, &payer).await.unwrap();
+        }
         "participating_miners" => {
             participating_miners(&rpc).await.unwrap();
         }
@@ -129,6 +202,9 @@ async fn main() {
         "init_token" => {
             init_token(&rpc, &payer).await.unwrap();
         }
+        "initialize" => {
+            initialize(&rpc, &payer).await.unwrap();
+        }
         _ => panic!("Invalid command"),
     };
 }
@@ -430,6 +506,32 @@ async fn init_token(
     Ok(())
 }
 
+async fn initialize(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let reward_per_round = u64_from_env("REWARD_PER_ROUND")?;
+    let max_supply = u64_from_env("MAX_SUPPLY")?;
+    let motherlode_bps = u64_from_env("MOTHERLODE_BPS")?;
+    let stake_bps = u64_from_env("STAKE_BPS")?;
+    let squares = optional_u64_from_env("SQUARES")?.unwrap_or(DEFAULT_SQUARES);
+    let ix = ore_api::sdk::initialize(
+        mint,
+        payer.pubkey(),
+        reward_per_round,
+        max_supply,
+        motherlode_bps,
+        stake_bps,
+        squares,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Config: {}", config_pda(mint).0);
+    println!("Board: {}", board_pda(mint).0);
+    println!("Treasury: {}", treasury_pda(mint).0);
+    Ok(())
+}
+
 async fn lut(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -523,6 +625,159 @@ async fn participating_miners(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+async fn stake_lock(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let days = u64_from_env("DAYS")?;
+    let ix = ore_api::sdk::lock_stake(mint, payer.pubkey(), days as i64 * ONE_DAY);
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Locked stake for {} days: {}", days, sig);
+    Ok(())
+}
+
+async fn expire_locks(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let clock = get_clock(rpc).await?;
+    let stakes = get_stakes(rpc).await?;
+    let mut ixs = vec![];
+    for (_address, stake) in stakes {
+        if stake.unlock_at > 0 && clock.unix_timestamp >= stake.unlock_at {
+            println!("Expire lock: {}", stake.authority);
+            ixs.push(ore_api::sdk::expire_lock(
+                mint,
+                payer.pubkey(),
+                stake.authority,
+            ));
+        }
+    }
+
+    // Batch and submit the instructions.
+    while !ixs.is_empty() {
+        let batch = ixs
+            .drain(..std::cmp::min(10, ixs.len()))
+            .collect::<Vec<Instruction>>();
+        submit_transaction(rpc, payer, &batch).await?;
+    }
+
+    Ok(())
+}
+
+async fn request_withdraw(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let amount = u64_from_env("AMOUNT")?;
+    let ix = ore_api::sdk::request_withdraw(mint, payer.pubkey(), amount);
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Requested withdrawal: {}", sig);
+    Ok(())
+}
+
+async fn complete_withdraw(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let ix = ore_api::sdk::complete_withdraw(mint, payer.pubkey());
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Completed withdrawal: {}", sig);
+    Ok(())
+}
+
+async fn transfer_stake(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let sig = match optional_keypair_from_env("NEW_AUTHORITY_KEYPAIR")? {
+        Some(new_authority) => {
+            // Co-sign as the new authority, so the stake can be merged into an existing account.
+            let ix = ore_api::sdk::transfer_stake(mint, payer.pubkey(), new_authority.pubkey());
+            submit_transaction_with_signers(rpc, payer, &[ix], &[&new_authority]).await?
+        }
+        None => {
+            let new_authority = pubkey_from_env("NEW_AUTHORITY")?;
+            if !new_authority.is_on_curve() {
+                // A PDA could never sign to withdraw or move the stake.
+                return Err(anyhow::anyhow!("NEW_AUTHORITY must be a wallet address"));
+            }
+            let ix = ore_api::sdk::transfer_stake(mint, payer.pubkey(), new_authority);
+            submit_transaction(rpc, payer, &[ix]).await?
+        }
+    };
+    println!("Transferred stake: {}", sig);
+    Ok(())
+}
+
+async fn initialize_receipt(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let ix = ore_api::sdk::initialize_receipt(mint, payer.pubkey());
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Initialized receipt mint: {}", sig);
+    Ok(())
+}
+
+async fn deposit_liquid(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let amount = u64_from_env("AMOUNT")?;
+    let ix = ore_api::sdk::deposit_liquid(mint, payer.pubkey(), amount);
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Deposited liquid stake: {}", sig);
+    Ok(())
+}
+
+async fn withdraw_liquid(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let shares = u64_from_env("AMOUNT")?;
+    let ix = ore_api::sdk::withdraw_liquid(mint, payer.pubkey(), shares);
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Withdrew liquid stake: {}", sig);
+    Ok(())
+}
+
+async fn log_receipt(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let treasury = get_treasury(rpc).await?;
+    let receipt_mint_address = ore_api::state::receipt_pda(mint).0;
+    let data = rpc.get_account_data(&receipt_mint_address).await?;
+    let receipt_mint = <spl_token::state::Mint as solana_sdk::program_pack::Pack>::unpack(&data)?;
+    let mut stake = get_stake(rpc, receipt_mint_address).await?;
+    stake.update_rewards(&treasury)?;
+    let value = stake.balance + stake.rewards;
+    println!("Receipt");
+    println!("  mint: {}", receipt_mint_address);
+    println!(
+        "  supply: {}",
+        amount_to_ui_amount(receipt_mint.supply, TOKEN_DECIMALS)
+    );
+    println!(
+        "  backing: {} ORE",
+        amount_to_ui_amount(value, TOKEN_DECIMALS)
+    );
+    if receipt_mint.supply > 0 {
+        println!(
+            "  exchange_rate: {} ORE",
+            value as f64 / receipt_mint.supply as f64
+        );
+    }
+    Ok(())
+}
+
 async fn log_stake(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -533,7 +788,7 @@ async fn log_stake(
     let treasury = get_treasury(&rpc).await?;
     let staker_address = ore_api::state::stake_pda(mint, authority).0;
     let mut stake = get_stake(rpc, authority).await?;
-    stake.update_rewards(&treasury);
+    stake.update_rewards(&treasury)?;
     println!("Stake");
     println!("  address: {}", staker_address);
     println!("  authority: {}", authority);
@@ -541,10 +796,14 @@ async fn log_stake(
         "  balance: {} ORE",
         amount_to_ui_amount(stake.balance, TOKEN_DECIMALS)
     );
-    println!("  buffer_a: {}", stake.buffer_a);
-    println!("  buffer_b: {}", stake.buffer_b);
-    println!("  buffer_c: {}", stake.buffer_c);
-    println!("  buffer_d: {}", stake.buffer_d);
+    println!("  unlock_at: {}", stake.unlock_at);
+    println!("  lock_multiplier: {} bps", stake.lock_multiplier);
+    println!("  weight: {}", stake.weight());
+    println!(
+        "  pending_withdraw: {} ORE",
+        amount_to_ui_amount(stake.pending_withdraw, TOKEN_DECIMALS)
+    );
+    println!("  withdraw_release_at: {}", stake.withdraw_release_at);
     println!(
         "  compound_fee_reserve: {} SOL",
         lamports_to_sol(stake.compound_fee_reserve)
@@ -610,12 +869,70 @@ async fn claim(
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let mint = mint_from_env()?;
-    let ix_sol = ore_api::sdk::claim_sol(mint, payer.pubkey());
-    let ix_ore = ore_api::sdk::claim_ore(mint, payer.pubkey());
+    let miner = get_miner(rpc, payer.pubkey()).await?;
+    let payout = miner.payout_address();
+    let ix_sol = ore_api::sdk::claim_sol(mint, payer.pubkey(), payout);
+    let ix_ore = ore_api::sdk::claim_ore(mint, payer.pubkey(), payout);
     submit_transaction(rpc, payer, &[ix_sol, ix_ore]).await?;
     Ok(())
 }
 
+async fn harvest(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let restake_bps = optional_u64_from_env("RESTAKE_BPS")?.unwrap_or(0);
+    let miner = get_miner(rpc, payer.pubkey()).await?;
+    let ix = ore_api::sdk::harvest(
+        mint,
+        payer.pubkey(),
+        miner.payout_address(),
+        miner.round_id,
+        restake_bps,
+    );
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Harvested: {}", sig);
+    Ok(())
+}
+
+async fn set_automation_limits(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let max_rounds = optional_u64_from_env("MAX_ROUNDS")?.unwrap_or(0);
+    let max_spend = optional_u64_from_env("MAX_SPEND")?.unwrap_or(0);
+    let expires_at = optional_u64_from_env("EXPIRES_AT")?.unwrap_or(0);
+    let stop_on_win = optional_u64_from_env("STOP_ON_WIN")?.unwrap_or(0) > 0;
+    let ix = ore_api::sdk::set_automation_limits(
+        mint,
+        payer.pubkey(),
+        max_rounds,
+        max_spend,
+        expires_at,
+        stop_on_win,
+    );
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Set automation limits: {}", sig);
+    Ok(())
+}
+
+async fn set_payout(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let payout = match std::env::var("PAYOUT") {
+        Ok(_) => pubkey_from_env("PAYOUT")?,
+        Err(_) => Pubkey::default(),
+    };
+    let ix = ore_api::sdk::set_payout(mint, payer.pubkey(), payout);
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Set payout address: {}", sig);
+    Ok(())
+}
+
 async fn buyback(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -724,36 +1041,81 @@ async fn reset(
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let board = get_board(rpc).await?;
-    let var = get_var(rpc, ORE_VAR_ADDRESS).await?;
-
-    println!("Var: {:?}", var);
+    let config = get_config(rpc).await?;
+    let round = get_round(rpc, board.round_id).await?;
+
+    // Read the randomness the round will be settled with.
+    let slot_hash = if round.randomness == RANDOMNESS_SLOT_HASHES {
+        let account = rpc.get_account(&sysvar::slot_hashes::ID).await?;
+        let slot_hashes =
+            bincode::deserialize::<solana_sdk::slot_hashes::SlotHashes>(&account.data)?;
+        slot_hashes
+            .iter()
+            .take_while(|(slot, _)| *slot >= board.end_slot)
+            .last()
+            .map(|(_, hash)| hash.to_bytes())
+            .unwrap_or([u8::MAX; 32])
+    } else {
+        let mut var = get_var(rpc, ORE_VAR_ADDRESS).await?;
+        println!("Var: {:?}", var);
+
+        // Finalize the var, if it has not been revealed yet.
+        if var.value == [0; 32] {
+            let client = reqwest::Client::new();
+            let url = format!("https://entropy-api.onrender.com/var/{ORE_VAR_ADDRESS}/seed");
+            let response = client
+                .get(url)
+                .send()
+                .await?
+                .json::<entropy_types::response::GetSeedResponse>()
+                .await?;
+            println!("Entropy seed: {:?}", response);
+
+            let sample_ix = entropy_api::sdk::sample(payer.pubkey(), ORE_VAR_ADDRESS);
+            let reveal_ix =
+                entropy_api::sdk::reveal(payer.pubkey(), ORE_VAR_ADDRESS, response.seed);
+            submit_transaction(rpc, payer, &[sample_ix, reveal_ix]).await?;
+            var = get_var(rpc, ORE_VAR_ADDRESS).await?;
+        }
+        var.value
+    };
 
-    let client = reqwest::Client::new();
-    let url = format!("https://entropy-api.onrender.com/var/{ORE_VAR_ADDRESS}/seed");
-    let response = client
-        .get(url)
-        .send()
+    // Find the top miner so it can be verified during reset.
+    let miners = get_miners_participating(rpc, board.round_id)
         .await?
-        .json::<entropy_types::response::GetSeedResponse>()
-        .await?;
-    println!("Entropy seed: {:?}", response);
-
-    let config = get_config(rpc).await?;
-    let sample_ix = entropy_api::sdk::sample(payer.pubkey(), ORE_VAR_ADDRESS);
-    let reveal_ix = entropy_api::sdk::reveal(payer.pubkey(), ORE_VAR_ADDRESS, response.seed);
+        .into_iter()
+        .map(|(_, miner)| miner)
+        .collect::<Vec<_>>();
+    let top_miner = ore_api::sdk::find_top_miner(&round, slot_hash, &miners).unwrap_or_default();
+    println!("Top miner: {}", top_miner);
+
+    // Migrate the round first, in case it was opened before the current layout.
+    let migrate_ix = ore_api::sdk::migrate_round(config.mint, payer.pubkey(), board.round_id);
     let reset_ix = ore_api::sdk::reset(
         config.mint,
         payer.pubkey(),
-        ADMIN_FEE_COLLECTOR,
+        config.fee_collector,
         board.round_id,
-        Pubkey::default(),
+        top_miner,
     );
-    let sig = submit_transaction(rpc, payer, &[sample_ix, reveal_ix, reset_ix]).await?;
+    let sig = submit_transaction(rpc, payer, &[migrate_ix, reset_ix]).await?;
     println!("Reset: {}", sig);
 
     Ok(())
 }
 
+async fn abort(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let board = get_board(rpc).await?;
+    let config = get_config(rpc).await?;
+    let ix = ore_api::sdk::abort(config.mint, payer.pubkey(), board.round_id);
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Abort: {}", sig);
+    Ok(())
+}
+
 async fn deploy(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -762,17 +1124,45 @@ async fn deploy(
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
     let square_id = std::env::var("SQUARE").expect("Missing SQUARE env var");
     let square_id = u64::from_str(&square_id).expect("Invalid SQUARE");
-    let mint = mint_from_env()?;
+    let config = get_config(rpc).await?;
     let board = get_board(rpc).await?;
-    let mut squares = [false; 25];
+    let mut squares = vec![false; config.squares as usize];
     squares[square_id as usize] = true;
     let ix = ore_api::sdk::deploy(
-        mint,
+        config.mint,
         payer.pubkey(),
         payer.pubkey(),
         amount,
         board.round_id,
-        squares,
+        &squares,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn deploy_weighted(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    // Parse comma separated square:amount pairs, e.g. "0:1000000,7:2500000".
+    let amounts = std::env::var("AMOUNTS").expect("Missing AMOUNTS env var");
+    let amounts = amounts
+        .split(',')
+        .map(|pair| {
+            let (square, amount) = pair
+                .split_once(':')
+                .ok_or_else(|| anyhow::anyhow!("Invalid AMOUNTS entry: {pair}"))?;
+            Ok((u8::from_str(square.trim())?, u64::from_str(amount.trim())?))
+        })
+        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+    let mint = mint_from_env()?;
+    let board = get_board(rpc).await?;
+    let ix = ore_api::sdk::deploy_weighted(
+        mint,
+        payer.pubkey(),
+        payer.pubkey(),
+        board.round_id,
+        &amounts,
     );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
@@ -784,16 +1174,16 @@ async fn deploy_all(
 ) -> Result<(), anyhow::Error> {
     let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
-    let mint = mint_from_env()?;
+    let config = get_config(rpc).await?;
     let board = get_board(rpc).await?;
-    let squares = [true; 25];
+    let squares = vec![true; config.squares as usize];
     let ix = ore_api::sdk::deploy(
-        mint,
+        config.mint,
         payer.pubkey(),
         payer.pubkey(),
         amount,
         board.round_id,
-        squares,
+        &squares,
     );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
@@ -802,9 +1192,123 @@ async fn deploy_all(
 async fn set_admin(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+
+    // The payer is the pending admin, accept the proposal.
+    if config.pending_admin == payer.pubkey() {
+        let ix = ore_api::sdk::accept_admin(config.mint, payer.pubkey());
+        submit_transaction(rpc, payer, &[ix]).await?;
+        println!("Accepted admin: {}", payer.pubkey());
+        return Ok(());
+    }
+
+    // If the new admin keypair is available, propose and accept in one transaction.
+    if let Some(new_admin) = optional_keypair_from_env("NEW_ADMIN_KEYPAIR")? {
+        let propose_ix =
+            ore_api::sdk::propose_admin(config.mint, payer.pubkey(), new_admin.pubkey());
+        let accept_ix = ore_api::sdk::accept_admin(config.mint, new_admin.pubkey());
+        submit_transaction_with_signers(rpc, payer, &[propose_ix, accept_ix], &[&new_admin])
+            .await?;
+        println!("Admin set: {}", new_admin.pubkey());
+        return Ok(());
+    }
+
+    // Otherwise, propose the new admin and wait for them to accept.
+    let new_admin = pubkey_from_env("ADMIN")?;
+    let ix = ore_api::sdk::propose_admin(config.mint, payer.pubkey(), new_admin);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Proposed admin: {} (pending acceptance)", new_admin);
+    Ok(())
+}
+
+async fn cancel_admin_proposal(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let mint = mint_from_env()?;
-    let ix = ore_api::sdk::set_admin(mint, payer.pubkey(), payer.pubkey());
+    let ix = ore_api::sdk::cancel_admin_proposal(mint, payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_config(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let reward_per_round =
+        optional_u64_from_env("REWARD_PER_ROUND")?.unwrap_or(config.reward_per_round);
+    let max_supply = optional_u64_from_env("MAX_SUPPLY")?.unwrap_or(config.max_supply);
+    let motherlode_bps =
+        optional_u64_from_env("MOTHERLODE_BPS")?.unwrap_or(config.motherlode_bps);
+    let stake_bps = optional_u64_from_env("STAKE_BPS")?.unwrap_or(config.stake_bps);
+    let round_slots = optional_u64_from_env("ROUND_SLOTS")?.unwrap_or(config.round_slots);
+    let intermission_slots =
+        optional_u64_from_env("INTERMISSION_SLOTS")?.unwrap_or(config.intermission_slots);
+    let expiry_slots = optional_u64_from_env("EXPIRY_SLOTS")?.unwrap_or(config.expiry_slots);
+    let abort_slots = optional_u64_from_env("ABORT_SLOTS")?.unwrap_or(config.abort_slots);
+    let randomness = optional_u64_from_env("RANDOMNESS")?.unwrap_or(config.randomness);
+    let withdraw_cooldown = optional_u64_from_env("WITHDRAW_COOLDOWN")?
+        .map(|cooldown| cooldown as i64)
+        .unwrap_or(config.withdraw_cooldown);
+    let ix = ore_api::sdk::set_config(
+        config.mint,
+        payer.pubkey(),
+        reward_per_round,
+        max_supply,
+        motherlode_bps,
+        stake_bps,
+        round_slots,
+        intermission_slots,
+        expiry_slots,
+        abort_slots,
+        randomness,
+        withdraw_cooldown,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_fee_collector(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let fee_collector = pubkey_from_env("FEE_COLLECTOR")?;
+    let ix = ore_api::sdk::set_fee_collector(mint, payer.pubkey(), fee_collector);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_fee_rate(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let fee_rate = u64_from_env("FEE_RATE")?;
+    let ix = ore_api::sdk::set_fee_rate(mint, payer.pubkey(), fee_rate);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_pause(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let paused = u64_from_env("PAUSED")?;
+    let ix = ore_api::sdk::set_pause(mint, payer.pubkey(), paused);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn migrate_config(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let ix = ore_api::sdk::migrate_config(mint, payer.pubkey());
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
@@ -822,14 +1326,81 @@ async fn checkpoint(
     Ok(())
 }
 
+async fn compound_all(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let clock = get_clock(rpc).await?;
+    let treasury = get_treasury(rpc).await?;
+    let stakes = get_stakes(rpc).await?;
+    let mint = mint_from_env()?;
+    let mut ixs = vec![];
+    for (i, (address, stake)) in stakes.iter().enumerate() {
+        // Skip stake accounts belonging to other mints.
+        if *address != ore_api::state::stake_pda(mint, stake.authority).0 {
+            continue;
+        }
+
+        // Skip stake accounts that cannot pay the fee or were compounded in the last day.
+        if stake.compound_fee_reserve < COMPOUND_FEE_PER_TRANSACTION
+            || stake.last_claim_at + ONE_DAY >= clock.unix_timestamp
+        {
+            continue;
+        }
+
+        // Skip stake accounts with nothing to compound.
+        let mut stake = *stake;
+        stake.update_rewards(&treasury)?;
+        if stake.rewards == 0 {
+            continue;
+        }
+
+        println!(
+            "[{}/{}] Compound stake: {} ({} ORE)",
+            i + 1,
+            stakes.len(),
+            stake.authority,
+            amount_to_ui_amount(stake.rewards, TOKEN_DECIMALS)
+        );
+        ixs.push(ore_api::sdk::compound_yield(
+            mint,
+            payer.pubkey(),
+            stake.authority,
+        ));
+    }
+
+    // Batch and submit the instructions.
+    while !ixs.is_empty() {
+        let batch = ixs
+            .drain(..std::cmp::min(5, ixs.len()))
+            .collect::<Vec<Instruction>>();
+        submit_transaction(rpc, payer, &batch).await?;
+    }
+
+    Ok(())
+}
+
 async fn checkpoint_all(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let clock = get_clock(rpc).await?;
-    let miners = get_miners(rpc).await?;
+    let config = get_config(rpc).await?;
+    let mut miners = get_miners(rpc).await?;
+    let legacy_miners = get_legacy_miners(rpc).await?;
+    let legacy_authorities = legacy_miners
+        .iter()
+        .map(|(_, miner)| miner.authority)
+        .collect::<HashSet<Pubkey>>();
+    miners.extend(legacy_miners);
     let mut expiry_slots = HashMap::new();
-    let mut ixs = vec![];
+    let mut authorities: HashMap<u64, Vec<Pubkey>> = HashMap::new();
+    let mut legacy: HashMap<u64, Vec<Pubkey>> = HashMap::new();
+    let legacy_rounds = get_legacy_rounds(rpc)
+        .await?
+        .into_iter()
+        .map(|(_, round)| round.id)
+        .collect::<HashSet<u64>>();
     let mint = mint_from_env()?;
     for (i, (_address, miner)) in miners.iter().enumerate() {
         if miner.checkpoint_id < miner.round_id {
@@ -846,7 +1417,7 @@ async fn checkpoint_all(
             };
 
             // If we are in fee collection period, checkpoint the miner.
-            if clock.slot >= expires_at - TWELVE_HOURS_SLOTS {
+            if clock.slot >= expires_at.saturating_sub(config.bot_fee_slots()) {
                 println!(
                     "[{}/{}] Checkpoint miner: {} ({} s)",
                     i + 1,
@@ -854,22 +1425,63 @@ async fn checkpoint_all(
                     miner.authority,
                     (expires_at - clock.slot) as f64 * 0.4
                 );
-                ixs.push(ore_api::sdk::checkpoint(
+                if legacy_authorities.contains(&miner.authority) {
+                    legacy
+                        .entry(miner.round_id)
+                        .or_default()
+                        .push(miner.authority);
+                } else {
+                    authorities
+                        .entry(miner.round_id)
+                        .or_default()
+                        .push(miner.authority);
+                }
+            }
+        }
+    }
+
+    // Migrate legacy miners and their round before checkpointing them.
+    for (round_id, mut authorities) in legacy {
+        while !authorities.is_empty() {
+            let batch = authorities
+                .drain(..std::cmp::min(5, authorities.len()))
+                .collect::<Vec<Pubkey>>();
+            let mut ixs = vec![ore_api::sdk::migrate_round(mint, payer.pubkey(), round_id)];
+            for authority in batch.iter() {
+                ixs.push(ore_api::sdk::migrate_miner(
                     mint,
                     payer.pubkey(),
-                    miner.authority,
-                    miner.round_id,
+                    *authority,
                 ));
             }
+            ixs.push(ore_api::sdk::checkpoint_many(
+                mint,
+                payer.pubkey(),
+                &batch,
+                round_id,
+            ));
+            submit_transaction(rpc, payer, &ixs).await?;
         }
     }
 
-    // Batch and submit the instructions.
-    while !ixs.is_empty() {
-        let batch = ixs
-            .drain(..std::cmp::min(10, ixs.len()))
-            .collect::<Vec<Instruction>>();
-        submit_transaction(rpc, payer, &batch).await?;
+    // Batch and submit the instructions, one round per transaction.
+    for (round_id, mut authorities) in authorities {
+        while !authorities.is_empty() {
+            let batch = authorities
+                .drain(..std::cmp::min(20, authorities.len()))
+                .collect::<Vec<Pubkey>>();
+            let mut ixs = vec![];
+            if legacy_rounds.contains(&round_id) {
+                ixs.push(ore_api::sdk::migrate_round(mint, payer.pubkey(), round_id));
+            }
+            ixs.push(ore_api::sdk::checkpoint_many(
+                mint,
+                payer.pubkey(),
+                &batch,
+                round_id,
+            ));
+            submit_transaction(rpc, payer, &ixs).await?;
+        }
     }
 
     Ok(())
@@ -906,6 +1518,90 @@ async fn close_all(
     Ok(())
 }
 
+async fn migrate_accounts(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let mut ixs = vec![];
+    for (address, round) in get_legacy_rounds(rpc).await? {
+        println!("Migrate round: {} ({})", round.id, address);
+        ixs.push(ore_api::sdk::migrate_round(mint, payer.pubkey(), round.id));
+    }
+    for (address, miner) in get_legacy_miners(rpc).await? {
+        println!("Migrate miner: {} ({})", miner.authority, address);
+        ixs.push(ore_api::sdk::migrate_miner(
+            mint,
+            payer.pubkey(),
+            miner.authority,
+        ));
+    }
+
+    // Batch and submit the instructions.
+    while !ixs.is_empty() {
+        let batch = ixs
+            .drain(..std::cmp::min(8, ixs.len()))
+            .collect::<Vec<Instruction>>();
+        submit_transaction(rpc, payer, &batch).await?;
+    }
+
+    Ok(())
+}
+
+async fn close_miners(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = mint_from_env()?;
+    let treasury = get_treasury(rpc).await?;
+    let wallets = match std::env::var("KEYPAIRS") {
+        Ok(paths) => paths
+            .split(',')
+            .map(|path| read_keypair_file(path.trim()).expect("Invalid KEYPAIRS"))
+            .collect::<Vec<Keypair>>(),
+        Err(_) => vec![payer.insecure_clone()],
+    };
+
+    // Find miners with nothing left to checkpoint or claim.
+    let mut closable = vec![];
+    for wallet in wallets.iter() {
+        let Ok(mut miner) = get_miner(rpc, wallet.pubkey()).await else {
+            continue;
+        };
+        miner.update_rewards(&treasury)?;
+        if miner.checkpoint_id != miner.round_id
+            || miner.rewards_sol > 0
+            || miner.rewards_ore > 0
+            || miner.refined_ore > 0
+        {
+            println!("Skipping miner: {} (unclaimed rewards)", wallet.pubkey());
+            continue;
+        }
+        let automation_address = automation_pda(mint, wallet.pubkey()).0;
+        if rpc.get_account(&automation_address).await.is_ok() {
+            println!("Skipping miner: {} (automation open)", wallet.pubkey());
+            continue;
+        }
+        println!("Closing miner: {}", wallet.pubkey());
+        closable.push(wallet);
+    }
+
+    // Batch and submit the instructions.
+    while !closable.is_empty() {
+        let batch = closable
+            .drain(..std::cmp::min(5, closable.len()))
+            .collect::<Vec<&Keypair>>();
+        let ixs = batch
+            .iter()
+            .map(|wallet| ore_api::sdk::close_miner(mint, wallet.pubkey()))
+            .collect::<Vec<Instruction>>();
+        let sig = submit_transaction_with_signers(rpc, payer, &ixs, &batch).await?;
+        println!("Closed miners: {}", sig);
+    }
+
+    Ok(())
+}
+
 async fn log_automation(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let authority = std::env::var("AUTHORITY").expect("Missing AUTHORITY env var");
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
@@ -927,6 +1623,32 @@ async fn log_automation(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     println!("  mask: {}", automation.mask);
     println!("  strategy: {}", automation.strategy);
     println!("  reload: {}", automation.reload);
+    println!("  version: {}", automation.version);
+    println!(
+        "  rounds: {} / {}",
+        automation.rounds_played, automation.max_rounds
+    );
+    println!(
+        "  spent: {} / {} SOL",
+        lamports_to_sol(automation.total_spent),
+        lamports_to_sol(automation.max_spend)
+    );
+    println!("  expires_at: {}", automation.expires_at);
+    println!("  stop_on_win: {}", automation.stop_on_win);
+    println!("  lifetime_rounds: {}", automation.lifetime_rounds);
+    println!(
+        "  lifetime_deployed: {} SOL",
+        lamports_to_sol(automation.lifetime_deployed)
+    );
+    println!(
+        "  lifetime_fees: {} SOL",
+        lamports_to_sol(automation.lifetime_fees)
+    );
+    println!(
+        "  lifetime_reloaded: {} SOL",
+        lamports_to_sol(automation.lifetime_reloaded)
+    );
+    println!("  last_round_id: {}", automation.last_round_id);
     Ok(())
 }
 
@@ -935,11 +1657,25 @@ async fn log_automations(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     for (i, (address, automation)) in automations.iter().enumerate() {
         println!("[{}/{}] {}", i + 1, automations.len(), address);
         println!("  authority: {}", automation.authority);
-        println!("  balance: {}", automation.balance);
+        println!("  balance: {} SOL", lamports_to_sol(automation.balance));
         println!("  executor: {}", automation.executor);
-        println!("  fee: {}", automation.fee);
+        println!("  fee: {} SOL", lamports_to_sol(automation.fee));
         println!("  mask: {}", automation.mask);
         println!("  strategy: {}", automation.strategy);
+        println!("  lifetime_rounds: {}", automation.lifetime_rounds);
+        println!(
+            "  lifetime_deployed: {} SOL",
+            lamports_to_sol(automation.lifetime_deployed)
+        );
+        println!(
+            "  lifetime_fees: {} SOL",
+            lamports_to_sol(automation.lifetime_fees)
+        );
+        println!(
+            "  lifetime_reloaded: {} SOL",
+            lamports_to_sol(automation.lifetime_reloaded)
+        );
+        println!("  last_round_id: {}", automation.last_round_id);
         println!();
     }
     Ok(())
@@ -990,10 +1726,13 @@ async fn log_round(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let rng = round.rng();
     println!("Round");
     println!("  Address: {}", round_address);
-    println!("  Count: {:?}", round.count);
-    println!("  Deployed: {:?}", round.deployed);
+    println!("  Squares: {}", round.squares);
+    println!("  Count: {:?}", &round.count[..round.squares as usize]);
+    println!("  Deployed: {:?}", &round.deployed[..round.squares as usize]);
     println!("  Expires at: {}", round.expires_at);
+    println!("  Fee rate: {}", round.fee_rate);
     println!("  Id: {:?}", round.id);
+    println!("  Randomness: {}", round.randomness);
     println!(
         "  Motherlode: {} ORE",
         amount_to_ui_amount(round.motherlode, TOKEN_DECIMALS)
@@ -1037,10 +1776,11 @@ async fn log_miner(
     let mint = mint_from_env()?;
     let miner_address = ore_api::state::miner_pda(mint, authority).0;
     let mut miner = get_miner(&rpc, authority).await?;
-    miner.update_rewards(&treasury);
+    miner.update_rewards(&treasury)?;
     println!("Miner");
     println!("  address: {}", miner_address);
     println!("  authority: {}", authority);
+    println!("  payout: {}", miner.payout_address());
     println!("  deployed: {:?}", miner.deployed);
     println!("  cumulative: {:?}", miner.cumulative);
     println!("  rewards_sol: {} SOL", lamports_to_sol(miner.rewards_sol));
@@ -1062,6 +1802,7 @@ async fn log_miner(
         "  lifetime_rewards_ore: {} ORE",
         amount_to_ui_amount(miner.lifetime_rewards_ore, TOKEN_DECIMALS)
     );
+    println!("  lifetime_wins: {}", miner.lifetime_wins);
     Ok(())
 }
 
@@ -1080,11 +1821,27 @@ async fn log_config(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let config = get_config(&rpc).await?;
     println!("Config");
     println!("  admin: {}", config.admin);
+    println!("  pending_admin: {}", config.pending_admin);
     println!("  mint: {}", config.mint);
     println!("  reward_per_round: {}", config.reward_per_round);
     println!("  max_supply: {}", config.max_supply);
     println!("  motherlode_bps: {}", config.motherlode_bps);
     println!("  stake_bps: {}", config.stake_bps);
+    println!("  fee_collector: {}", config.fee_collector);
+    println!("  fee_rate: {}", config.fee_rate);
+    println!("  squares: {}", config.squares);
+    println!("  round_slots: {}", config.round_slots);
+    println!("  intermission_slots: {}", config.intermission_slots);
+    println!("  expiry_slots: {}", config.expiry_slots);
+    println!("  abort_slots: {}", config.abort_slots);
+    println!("  randomness: {}", config.randomness);
+    println!("  withdraw_cooldown: {}s", config.withdraw_cooldown);
+    println!("  paused: {:#07b}", config.paused);
+    println!("    mining: {}", config.is_paused(PAUSE_MINING));
+    println!("    claims: {}", config.is_paused(PAUSE_CLAIMS));
+    println!("    staking: {}", config.is_paused(PAUSE_STAKING));
+    println!("    withdrawals: {}", config.is_paused(PAUSE_WITHDRAWALS));
+    println!("    treasury: {}", config.is_paused(PAUSE_TREASURY));
     Ok(())
 }
 
@@ -1119,6 +1876,14 @@ fn u64_from_env(name: &str) -> Result<u64, anyhow::Error> {
     Ok(u64::from_str(&value).map_err(|err| anyhow::anyhow!("{name} invalid: {err}"))?)
 }
 
+fn optional_u64_from_env(name: &str) -> Result<Option<u64>, anyhow::Error> {
+    Ok(std::env::var(name)
+        .ok()
+        .map(|value| u64::from_str(&value))
+        .transpose()
+        .map_err(|err| anyhow::anyhow!("{name} invalid: {err}"))?)
+}
+
 fn env_flag(name: &str) -> bool {
     std::env::var(name)
         .ok()
@@ -1400,6 +2165,9 @@ async fn get_round(rpc: &RpcClient, id: u64) -> Result<Round, anyhow::Error> {
     let mint = mint_from_env()?;
     let round_pda = ore_api::state::round_pda(mint, id);
     let account = rpc.get_account(&round_pda.0).await?;
+    if let Ok(round) = LegacyRound::try_from_bytes(&account.data) {
+        return Ok(round.migrate());
+    }
     let round = Round::try_from_bytes(&account.data)?;
     Ok(*round)
 }
@@ -1442,6 +2210,11 @@ async fn get_stake(rpc: &RpcClient, authority: Pubkey) -> Result<Stake, anyhow::
     Ok(*stake)
 }
 
+async fn get_stakes(rpc: &RpcClient) -> Result<Vec<(Pubkey, Stake)>, anyhow::Error> {
+    let stakes = get_program_accounts::<Stake>(rpc, ore_api::ID, vec![]).await?;
+    Ok(stakes)
+}
+
 async fn get_rounds(rpc: &RpcClient) -> Result<Vec<(Pubkey, Round)>, anyhow::Error> {
     let rounds = get_program_accounts::<Round>(rpc, ore_api::ID, vec![]).await?;
     Ok(rounds)
@@ -1453,11 +2226,31 @@ async fn get_miners(rpc: &RpcClient) -> Result<Vec<(Pubkey, Miner)>, anyhow::Err
     Ok(miners)
 }
 
+/// Returns miners still on the legacy layout, converted to the current layout.
+async fn get_legacy_miners(rpc: &RpcClient) -> Result<Vec<(Pubkey, Miner)>, anyhow::Error> {
+    let miners = get_program_accounts::<LegacyMiner>(rpc, ore_api::ID, vec![]).await?;
+    Ok(miners
+        .into_iter()
+        .map(|(address, miner)| (address, miner.migrate()))
+        .collect())
+}
+
+/// Returns rounds still on the legacy layout, converted to the current layout.
+async fn get_legacy_rounds(rpc: &RpcClient) -> Result<Vec<(Pubkey, Round)>, anyhow::Error> {
+    let rounds = get_program_accounts::<LegacyRound>(rpc, ore_api::ID, vec![]).await?;
+    Ok(rounds
+        .into_iter()
+        .map(|(address, round)| (address, round.migrate()))
+        .collect())
+}
+
 async fn get_miners_participating(
     rpc: &RpcClient,
     round_id: u64,
 ) -> Result<Vec<(Pubkey, Miner)>, anyhow::Error> {
-    let filter = RpcFilterType::Memcmp(Memcmp::new_base58_encoded(512, &round_id.to_le_bytes()));
+    let offset = 8 + std::mem::offset_of!(Miner, round_id);
+    let filter =
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(offset, &round_id.to_le_bytes()));
     let miners = get_program_accounts::<Miner>(rpc, ore_api::ID, vec![filter]).await?;
     Ok(miners)
 }
@@ -1473,6 +2266,17 @@ async fn get_miners_participating(
 //     r % 25
 // }
 
+/// Prints a readable message if a transaction failed with an ORE program error.
+fn log_ore_error(err: &ClientError) {
+    if let Some(TransactionError::InstructionError(_, InstructionError::Custom(code))) =
+        err.get_transaction_error()
+    {
+        if let Ok(ore_error) = OreError::try_from(code) {
+            println!("ORE error {}: {}", code, ore_error);
+        }
+    }
+}
+
 #[allow(dead_code)]
 async fn simulate_transaction(
     rpc: &RpcClient,
@@ -1577,6 +2381,7 @@ async fn submit_transaction(
             Ok(signature)
         }
         Err(e) => {
+            log_ore_error(&e);
             println!("Error submitting transaction: {:?}", e);
             Err(e.into())
         }
@@ -1610,6 +2415,7 @@ async fn submit_transaction_with_signers(
             Ok(signature)
         }
         Err(e) => {
+            log_ore_error(&e);
             println!("Error submitting transaction: {:?}", e);
             Err(e.into())
         }
@@ -1640,6 +2446,7 @@ async fn submit_transaction_no_confirm(
             Ok(signature)
         }
         Err(e) => {
+            log_ore_error(&e);
             println!("Error submitting transaction: {:?}", e);
             Err(e.into())
         }
@@ -0,0 +1,130 @@
+use entropy_api::state::Var;
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Refunds a round whose entropy was never revealed and advances the board.
+pub fn process_abort(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, board_info, config_info, round_info, round_next_info, var_info, system_program, ore_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_MINING) {
+        return Err(OreError::Paused.into());
+    }
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    let board = board_info
+        .as_account_mut::<Board>(&ore_api::ID)?
+        .assert_mut_err(
+            |b| {
+                config.abort_slots > 0
+                    && clock.slot >= b.end_slot.saturating_add(config.abort_slots)
+            },
+            OreError::RoundNotAbortable.into(),
+        )?;
+    let round = round_info
+        .as_account_mut::<Round>(&ore_api::ID)?
+        .assert_mut(|r| r.id == board.round_id)?
+        .assert_mut_err(
+            |r| r.randomness == RANDOMNESS_ENTROPY,
+            OreError::RoundNotAbortable.into(),
+        )?;
+    round_info.has_seeds(
+        &[
+            ROUND,
+            &config.mint.to_bytes(),
+            &board.round_id.to_le_bytes(),
+        ],
+        &ore_api::ID,
+    )?;
+    round_next_info.is_empty()?.is_writable()?.has_seeds(
+        &[
+            ROUND,
+            &config.mint.to_bytes(),
+            &(board.round_id + 1).to_le_bytes(),
+        ],
+        &ore_api::ID,
+    )?;
+    var_info
+        .has_address(&VAR_ADDRESS)?
+        .as_account::<Var>(&entropy_api::ID)?
+        .assert(|v| v.authority == *board_info.key)?
+        .assert_err(|v| v.value == [0; 32], OreError::RoundNotAbortable.into())?;
+    system_program.is_program(&system_program::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Open next round account.
+    create_program_account::<Round>(
+        round_next_info,
+        ore_program,
+        signer_info,
+        &ore_api::ID,
+        &[
+            ROUND,
+            &config.mint.to_bytes(),
+            &(board.round_id + 1).to_le_bytes(),
+        ],
+    )?;
+    let round_next = round_next_info.as_account_mut::<Round>(&ore_api::ID)?;
+    round_next.id = board.round_id + 1;
+    round_next.deployed = [0; MAX_SQUARES];
+    round_next.slot_hash = [0; 32];
+    round_next.count = [0; MAX_SQUARES];
+    round_next.expires_at = u64::MAX; // Set to max, to indicate round is waiting for first deploy to begin.
+    round_next.rent_payer = *signer_info.key;
+    round_next.motherlode = 0;
+    round_next.top_miner = Pubkey::default();
+    round_next.top_miner_reward = 0;
+    round_next.total_deployed = 0;
+    round_next.total_vaulted = 0;
+    round_next.total_winnings = 0;
+    round_next.squares = config.squares;
+    round_next.fee_rate = 0;
+    round_next.randomness = config.randomness;
+
+    // Mark the round as having no randomness, so miners are refunded on checkpoint.
+    sol_log(&format!("Aborting round #{}", round.id).as_str());
+    round.slot_hash = [u8::MAX; 32];
+    round.total_vaulted = 0;
+    round.total_winnings = 0;
+    round.total_deployed = 0;
+
+    // Give miners the full expiry window to checkpoint their refunds.
+    round.expires_at = clock.slot + config.expiry_slots;
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        ResetEvent {
+            disc: OreEvent::Reset as u64,
+            round_id: round.id,
+            start_slot: board.start_slot,
+            end_slot: board.end_slot,
+            winning_square: u64::MAX,
+            top_miner: Pubkey::default(),
+            num_winners: 0,
+            motherlode: 0,
+            total_deployed: round.total_deployed,
+            total_vaulted: round.total_vaulted,
+            total_winnings: round.total_winnings,
+            total_minted: 0,
+            randomness: round.randomness,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    // Update board for next round.
+    board.round_id += 1;
+    board.start_slot = clock.slot + 1;
+    board.end_slot = u64::MAX;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Accepts a pending admin proposal. Must be signed by the proposed admin.
+pub fn process_accept_admin(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account_mut::<Config>(&ore_api::ID)?;
+    config.assert_mut_err(
+        |c| c.pending_admin != Pubkey::default(),
+        OreError::NoPendingAdmin.into(),
+    )?;
+    config.assert_mut_err(
+        |c| c.pending_admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+
+    // Transfer admin.
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    Ok(())
+}
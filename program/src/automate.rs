@@ -1,4 +1,5 @@
 use ore_api::prelude::*;
+use solana_program::rent::Rent;
 use steel::*;
 
 /// Sets the executor.
@@ -9,7 +10,7 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     let deposit = u64::from_le_bytes(args.deposit);
     let fee = u64::from_le_bytes(args.fee);
     let mask = u64::from_le_bytes(args.mask);
-    let strategy = AutomationStrategy::from_u64(args.strategy as u64);
+    let strategy = AutomationStrategy::from_u64(args.strategy as u64)?;
     let reload = u64::from_le_bytes(args.reload) > 0;
 
     // Load accounts.
@@ -21,6 +22,9 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     signer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_MINING) {
+        return Err(OreError::Paused.into());
+    }
     automation_info.is_writable()?;
     system_program.is_program(&system_program::ID)?;
 
@@ -35,8 +39,8 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         )?;
         let miner = miner_info.as_account_mut::<Miner>(&ore_api::ID)?;
         miner.authority = *signer_info.key;
-        miner.deployed = [0; 25];
-        miner.cumulative = [0; 25];
+        miner.deployed = [0; MAX_SQUARES];
+        miner.cumulative = [0; MAX_SQUARES];
         miner.checkpoint_fee = 0;
         miner.checkpoint_id = 0;
         miner.rewards_sol = 0;
@@ -44,6 +48,8 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         miner.round_id = 0;
         miner.lifetime_rewards_sol = 0;
         miner.lifetime_rewards_ore = 0;
+        miner.lifetime_wins = 0;
+        miner.payout = Pubkey::default();
         miner
     } else {
         miner_info.has_seeds(
@@ -86,6 +92,19 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         let automation = automation_info.as_account_mut::<Automation>(&ore_api::ID)?;
         automation.balance = 0;
         automation.authority = *signer_info.key;
+        automation.version = AUTOMATION_VERSION;
+        automation.max_rounds = 0;
+        automation.rounds_played = 0;
+        automation.max_spend = 0;
+        automation.total_spent = 0;
+        automation.expires_at = 0;
+        automation.stop_on_win = 0;
+        automation.last_wins = miner.lifetime_wins;
+        automation.lifetime_rounds = 0;
+        automation.lifetime_deployed = 0;
+        automation.lifetime_fees = 0;
+        automation.lifetime_reloaded = 0;
+        automation.last_round_id = 0;
         automation
     } else {
         automation_info.has_seeds(
@@ -96,6 +115,10 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
             ],
             &ore_api::ID,
         )?;
+        let upgrade_rent = upgrade_automation(automation_info)?;
+        if upgrade_rent > 0 {
+            automation_info.collect(upgrade_rent, signer_info)?;
+        }
         automation_info
             .as_account_mut::<Automation>(&ore_api::ID)?
             .assert_mut_err(
@@ -124,3 +147,18 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
 
     Ok(())
 }
+
+/// Grows an automation account created before limits were added to the current layout, and
+/// returns the additional rent the larger account requires.
+pub fn upgrade_automation(automation_info: &AccountInfo<'_>) -> Result<u64, ProgramError> {
+    let size = 8 + std::mem::size_of::<Automation>();
+    let old_size = automation_info.data_len();
+    if old_size >= size {
+        return Ok(0);
+    }
+    automation_info.realloc(size, true)?;
+    let automation = automation_info.as_account_mut::<Automation>(&ore_api::ID)?;
+    automation.version = AUTOMATION_VERSION;
+    let rent = Rent::get()?;
+    Ok(rent.minimum_balance(size) - rent.minimum_balance(old_size))
+}
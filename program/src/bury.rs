@@ -18,6 +18,9 @@ pub fn process_bury(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
     signer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_TREASURY) {
+        return Err(OreError::Paused.into());
+    }
     let sender = sender_info
         .is_writable()?
         .as_associated_token_account(&signer_info.key, &config.mint)?;
@@ -7,6 +7,9 @@ use steel::*;
 /// Swap vaulted SOL to ORE, and burn the ORE.
 pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Load accounts.
+    if accounts.len() < 9 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
     let (ore_accounts, swap_accounts) = accounts.split_at(9);
     let [signer_info, board_info, config_info, mint_info, treasury_info, treasury_ore_info, treasury_sol_info, token_program, ore_program] =
         ore_accounts
@@ -17,6 +20,9 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     board_info.as_account_mut::<Board>(&ore_api::ID)?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_TREASURY) {
+        return Err(OreError::Paused.into());
+    }
     board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
     let ore_mint = mint_info.has_address(&config.mint)?.as_mint()?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
@@ -35,7 +41,9 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
         treasury_sol_info.as_associated_token_account(treasury_info.key, &SOL_MINT)?;
     let pre_swap_ore_balance = treasury_ore.amount();
     let pre_swap_sol_balance = treasury_sol.amount();
-    assert!(pre_swap_sol_balance > 0);
+    if pre_swap_sol_balance == 0 {
+        return Err(OreError::AmountTooSmall.into());
+    }
 
     // Record pre-swap mint supply.
     let pre_swap_mint_supply = ore_mint.supply();
@@ -76,19 +84,23 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
 
     // Record post-swap treasury lamports.
     let post_swap_treasury_lamports = treasury_info.lamports();
-    assert_eq!(
-        post_swap_treasury_lamports, pre_swap_treasury_lamports,
-        "Treasury lamports changed during swap: {} -> {}",
-        pre_swap_treasury_lamports, post_swap_treasury_lamports
-    );
+    if post_swap_treasury_lamports != pre_swap_treasury_lamports {
+        sol_log(&format!(
+            "Treasury lamports changed during swap: {} -> {}",
+            pre_swap_treasury_lamports, post_swap_treasury_lamports
+        ));
+        return Err(OreError::SwapChangedTreasury.into());
+    }
 
     // Record post-swap mint supply.
     let post_swap_mint_supply = mint_info.as_mint()?.supply();
-    assert_eq!(
-        post_swap_mint_supply, pre_swap_mint_supply,
-        "Mint supply changed during swap: {} -> {}",
-        pre_swap_mint_supply, post_swap_mint_supply
-    );
+    if post_swap_mint_supply != pre_swap_mint_supply {
+        sol_log(&format!(
+            "Mint supply changed during swap: {} -> {}",
+            pre_swap_mint_supply, post_swap_mint_supply
+        ));
+        return Err(OreError::SwapChangedSupply.into());
+    }
 
     // Record post-swap balances.
     let treasury_ore =
@@ -97,9 +109,10 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
         treasury_sol_info.as_associated_token_account(treasury_info.key, &SOL_MINT)?;
     let post_swap_ore_balance = treasury_ore.amount();
     let post_swap_sol_balance = treasury_sol.amount();
+    if post_swap_sol_balance != 0 || post_swap_ore_balance < pre_swap_ore_balance {
+        return Err(OreError::SwapIncomplete.into());
+    }
     let total_ore = post_swap_ore_balance - pre_swap_ore_balance;
-    assert_eq!(post_swap_sol_balance, 0);
-    assert!(post_swap_ore_balance >= pre_swap_ore_balance);
     sol_log(
         &format!(
             "📈 Swapped {} SOL into {} ORE",
@@ -0,0 +1,26 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Cancels a pending admin proposal.
+pub fn process_cancel_admin_proposal(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account_mut::<Config>(&ore_api::ID)?;
+    config.assert_mut_err(
+        |c| c.admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    config.assert_mut_err(
+        |c| c.pending_admin != Pubkey::default(),
+        OreError::NoPendingAdmin.into(),
+    )?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+
+    // Clear pending admin.
+    config.pending_admin = Pubkey::default();
+
+    Ok(())
+}
@@ -6,8 +6,7 @@ use steel::*;
 /// Checkpoints a miner's rewards.
 pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
-    let clock = Clock::get()?;
-    let [signer_info, config_info, board_info, miner_info, round_info, treasury_info, system_program] =
+    let [signer_info, config_info, board_info, miner_info, round_info, treasury_info, system_program, ore_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -15,6 +14,9 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     signer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_CLAIMS) {
+        return Err(OreError::Paused.into());
+    }
     let board = board_info.as_account::<Board>(&ore_api::ID)?;
     board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
     let miner = miner_info.as_account_mut::<Miner>(&ore_api::ID)?;
@@ -25,10 +27,48 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
     treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
     system_program.is_program(&system_program::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Checkpoint miner.
+    let Some(event) = checkpoint_miner(
+        signer_info,
+        miner_info,
+        round_info,
+        config,
+        board,
+        miner,
+        treasury,
+    )?
+    else {
+        return Ok(());
+    };
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        event.to_bytes(),
+    )?;
+
+    Ok(())
+}
+
+/// Checkpoints a miner's rewards from the round they last played in and pays any bot fee to the
+/// signer. Returns the event to emit, or none if there was nothing to checkpoint.
+pub fn checkpoint_miner<'a>(
+    signer_info: &AccountInfo<'a>,
+    miner_info: &AccountInfo<'a>,
+    round_info: &AccountInfo<'a>,
+    config: &Config,
+    board: &Board,
+    miner: &mut Miner,
+    treasury: &mut Treasury,
+) -> Result<Option<CheckpointEvent>, ProgramError> {
+    let clock = Clock::get()?;
 
     // If miner has already checkpointed this round, return.
     if miner.checkpoint_id == miner.round_id {
-        return Ok(());
+        return Ok(None);
     }
 
     // If round account is empty, verify the correct account was provided.
@@ -45,7 +85,7 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
             &ore_api::ID,
         )?;
         miner.checkpoint_id = miner.round_id;
-        return Ok(());
+        return Ok(None);
     }
 
     // If round is current round, or the miner round ID does not match the provided round, return.
@@ -53,7 +93,7 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     sol_log(&format!("Round ID: {}", round.id).as_str());
     if round.id == board.round_id || round.id != miner.round_id || round.slot_hash == [0; 32] {
         sol_log(&format!("Round not valid").as_str());
-        return Ok(());
+        return Ok(None);
     }
 
     // Ensure round is not expired.
@@ -61,13 +101,13 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     if clock.slot >= round.expires_at {
         sol_log(&format!("Round expired").as_str());
         miner.checkpoint_id = miner.round_id;
-        return Ok(());
+        return Ok(None);
     }
 
     // Calculate bot fee.
-    // If the round expires in less than 12h, anyone may checkpoint this account and collect the bot fee.
+    // If the round is close to expiring, anyone may checkpoint this account and collect the bot fee.
     let mut bot_fee = 0;
-    if clock.slot >= round.expires_at - TWELVE_HOURS_SLOTS {
+    if clock.slot >= round.expires_at.saturating_sub(config.bot_fee_slots()) {
         bot_fee = miner.checkpoint_fee;
         miner.checkpoint_fee = 0;
     }
@@ -85,14 +125,20 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
         // If the miner deployed to the winning square, calculate rewards.
         if miner.deployed[winning_square] > 0 {
             // Sanity check.
-            assert!(
-                round.deployed[winning_square] >= miner.deployed[winning_square],
-                "Invalid round deployed amount"
-            );
+            if round.deployed[winning_square] < miner.deployed[winning_square] {
+                return Err(OreError::InvalidRoundDeployed.into());
+            }
+
+            // Record the win.
+            miner.lifetime_wins += 1;
 
             // Calculate SOL rewards.
             let original_deployment = miner.deployed[winning_square];
-            let admin_fee = (original_deployment / 100).max(1);
+            let admin_fee = if round.fee_rate > 0 {
+                round.admin_fee(original_deployment).max(1)
+            } else {
+                0
+            };
             rewards_sol = original_deployment - admin_fee;
             rewards_sol += ((round.total_winnings as u128 * miner.deployed[winning_square] as u128)
                 / round.deployed[winning_square] as u128) as u64;
@@ -114,10 +160,7 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
             } else {
                 // If round is not split, payout to the top miner.
                 let top_miner_sample = round.top_miner_sample(r, winning_square);
-                if top_miner_sample >= miner.cumulative[winning_square]
-                    && top_miner_sample
-                        < miner.cumulative[winning_square] + miner.deployed[winning_square]
-                {
+                if round.is_top_miner(miner, winning_square, top_miner_sample) {
                     rewards_ore = round.top_miner_reward.min(top_miner_reward);
                     round.top_miner = miner.authority;
                     sol_log(
@@ -148,10 +191,9 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     } else {
         // Sanity check.
         // If there is no rng, total deployed should have been reset to zero.
-        assert!(
-            round.total_deployed == 0,
-            "Round total deployed should be zero."
-        );
+        if round.total_deployed != 0 {
+            return Err(OreError::InvalidRoundTotals.into());
+        }
 
         // Round has no slot hash, refund all SOL.
         let refund_amount = miner.deployed.iter().sum::<u64>();
@@ -160,7 +202,7 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     }
 
     // Checkpoint rewards.
-    miner.update_rewards(treasury);
+    miner.update_rewards(treasury)?;
 
     // Checkpoint miner.
     miner.checkpoint_id = round.id;
@@ -172,21 +214,31 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     // Update treasury.
     treasury.total_unclaimed += rewards_ore;
 
-    // Do SOL transfers.
+    // Transfer SOL rewards.
     if rewards_sol > 0 {
-        round_info.send(rewards_sol, &miner_info);
+        round_info.send(rewards_sol, miner_info);
     }
+
+    // Pay bot fee.
     if bot_fee > 0 {
-        miner_info.send(bot_fee, &signer_info);
+        miner_info.send(bot_fee, signer_info);
     }
 
     // Assert miner account has sufficient funds for rent and rewards.
     let account_size = 8 + std::mem::size_of::<Miner>();
     let required_rent = Rent::get()?.minimum_balance(account_size);
-    assert!(
-        miner_info.lamports() >= required_rent + miner.checkpoint_fee + miner.rewards_sol,
-        "Miner does not have sufficient funds for rent and rewards"
-    );
+    if miner_info.lamports() < required_rent + miner.checkpoint_fee + miner.rewards_sol {
+        return Err(OreError::InsufficientMinerBalance.into());
+    }
 
-    Ok(())
+    Ok(Some(CheckpointEvent {
+        disc: OreEvent::Checkpoint as u64,
+        authority: miner.authority,
+        round_id: round.id,
+        rewards_sol,
+        rewards_ore,
+        bot_fee,
+        signer: *signer_info.key,
+        ts: clock.unix_timestamp,
+    }))
 }
@@ -0,0 +1,58 @@
+use ore_api::prelude::*;
+use steel::*;
+
+use crate::checkpoint_miner;
+
+/// Checkpoints the rewards of many miners from the same round.
+pub fn process_checkpoint_many(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, board_info, round_info, treasury_info, system_program, ore_program, miner_infos @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_CLAIMS) {
+        return Err(OreError::Paused.into());
+    }
+    let board = board_info.as_account::<Board>(&ore_api::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Checkpoint each miner.
+    for miner_info in miner_infos {
+        let miner = miner_info
+            .is_writable()?
+            .as_account_mut::<Miner>(&ore_api::ID)?;
+        miner_info.has_seeds(
+            &[MINER, &config.mint.to_bytes(), &miner.authority.to_bytes()],
+            &ore_api::ID,
+        )?;
+        let Some(event) = checkpoint_miner(
+            signer_info,
+            miner_info,
+            round_info,
+            config,
+            board,
+            miner,
+            treasury,
+        )?
+        else {
+            continue;
+        };
+
+        // Emit event.
+        program_log(
+            config.mint,
+            &[board_info.clone(), ore_program.clone()],
+            event.to_bytes(),
+        )?;
+    }
+
+    Ok(())
+}
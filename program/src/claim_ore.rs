@@ -3,11 +3,12 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
-/// Claims a block reward.
+/// Claims a block reward. The payout address may be passed as an optional trailing account, and
+/// defaults to the signer.
 pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, config_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+    let [signer_info, config_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, board_info, ore_program, payout_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -15,6 +16,9 @@ pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
     signer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_CLAIMS) {
+        return Err(OreError::Paused.into());
+    }
     miner_info.has_seeds(
         &[MINER, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
         &ore_api::ID,
@@ -23,6 +27,8 @@ pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
         .as_account_mut::<Miner>(&ore_api::ID)?
         .assert_mut(|m| m.authority == *signer_info.key)?;
     let mint = mint_info.has_address(&config.mint)?.as_mint()?;
+    let payout_info = payout_accounts.first().unwrap_or(signer_info);
+    payout_info.has_address(&miner.payout_address())?;
     recipient_info.is_writable()?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
     treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
@@ -30,12 +36,14 @@ pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
     system_program.is_program(&system_program::ID)?;
     token_program.is_program(&spl_token::ID)?;
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
 
     // Load recipient.
     if recipient_info.data_is_empty() {
         create_associated_token_account(
             signer_info,
-            signer_info,
+            payout_info,
             recipient_info,
             mint_info,
             system_program,
@@ -43,11 +51,11 @@ pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
             associated_token_program,
         )?;
     } else {
-        recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+        recipient_info.as_associated_token_account(payout_info.key, mint_info.key)?;
     }
 
     // Normalize amount.
-    let amount = miner.claim_ore(&clock, treasury);
+    let amount = miner.claim_ore(&clock, treasury)?;
 
     sol_log(
         &format!(
@@ -67,5 +75,20 @@ pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
         &[TREASURY, &config.mint.to_bytes()],
     )?;
 
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        ClaimEvent {
+            disc: OreEvent::Claim as u64,
+            authority: *signer_info.key,
+            kind: ClaimKind::Ore as u64,
+            amount,
+            recipient: *recipient_info.key,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
     Ok(())
 }
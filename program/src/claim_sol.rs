@@ -2,16 +2,22 @@ use ore_api::prelude::*;
 use solana_program::{log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
-/// Claims a block reward.
+/// Claims a block reward. The payout address may be passed as an optional trailing account, and
+/// defaults to the signer.
 pub fn process_claim_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, config_info, miner_info, system_program] = accounts else {
+    let [signer_info, config_info, miner_info, system_program, board_info, ore_program, payout_accounts @ ..] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_CLAIMS) {
+        return Err(OreError::Paused.into());
+    }
     miner_info.has_seeds(
         &[MINER, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
         &ore_api::ID,
@@ -19,7 +25,13 @@ pub fn process_claim_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
     let miner = miner_info
         .as_account_mut::<Miner>(&ore_api::ID)?
         .assert_mut(|m| m.authority == *signer_info.key)?;
+    let recipient_info = payout_accounts.first().unwrap_or(signer_info);
+    recipient_info
+        .is_writable()?
+        .has_address(&miner.payout_address())?;
     system_program.is_program(&system_program::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
 
     // Normalize amount.
     let amount = miner.claim_sol(&clock);
@@ -27,7 +39,22 @@ pub fn process_claim_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
     sol_log(&format!("Claiming {} SOL", lamports_to_sol(amount)).as_str());
 
     // Transfer reward to recipient.
-    miner_info.send(amount, signer_info);
+    miner_info.send(amount, recipient_info);
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        ClaimEvent {
+            disc: OreEvent::Claim as u64,
+            authority: *signer_info.key,
+            kind: ClaimKind::Sol as u64,
+            amount,
+            recipient: *recipient_info.key,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
 
     Ok(())
 }
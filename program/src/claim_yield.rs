@@ -11,7 +11,7 @@ pub fn process_claim_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
 
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, config_info, mint_info, recipient_info, stake_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+    let [signer_info, config_info, mint_info, recipient_info, stake_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, board_info, ore_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -19,6 +19,9 @@ pub fn process_claim_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
     signer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_WITHDRAWALS) {
+        return Err(OreError::Paused.into());
+    }
     let mint = mint_info.has_address(&config.mint)?.as_mint()?;
     recipient_info.is_writable()?;
     stake_info.has_seeds(
@@ -36,6 +39,8 @@ pub fn process_claim_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
     system_program.is_program(&system_program::ID)?;
     token_program.is_program(&spl_token::ID)?;
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
 
     // Open recipient token account.
     if recipient_info.data_is_empty() {
@@ -53,7 +58,7 @@ pub fn process_claim_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
     }
 
     // Claim yield from stake account.
-    let amount = stake.claim(amount, &clock, treasury);
+    let amount = stake.claim(amount, &clock, treasury)?;
 
     // Transfer ORE to recipient.
     transfer_signed(
@@ -74,5 +79,20 @@ pub fn process_claim_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
         .as_str(),
     );
 
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        ClaimEvent {
+            disc: OreEvent::Claim as u64,
+            authority: *signer_info.key,
+            kind: ClaimKind::Yield as u64,
+            amount,
+            recipient: *recipient_info.key,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
     Ok(())
 }
@@ -2,7 +2,8 @@ use ore_api::prelude::*;
 use solana_program::rent::Rent;
 use steel::*;
 
-/// Closes a round accound, and returns the rent to the rent payer.
+/// Closes a round accound, and returns the rent to the rent payer. Paused with claims, so unclaimed
+/// SOL is not vaulted while miners are unable to checkpoint.
 pub fn process_close(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
@@ -14,13 +15,19 @@ pub fn process_close(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     signer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_CLAIMS) {
+        return Err(OreError::Paused.into());
+    }
     let board = board_info.as_account_mut::<Board>(&ore_api::ID)?;
     board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
     rent_payer_info.is_writable()?;
     round_info
         .as_account_mut::<Round>(&ore_api::ID)?
         .assert_mut(|r| r.id < board.round_id)?
-        .assert_mut(|r| r.expires_at < clock.slot)? // Ensure round has expired.
+        .assert_mut_err(
+            |r| r.expires_at < clock.slot,
+            OreError::RoundNotExpired.into(),
+        )? // Ensure round has expired.
         .assert_mut(|r| r.rent_payer == *rent_payer_info.key)?; // Ensure the rent payer is the correct one.
     let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
     treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
@@ -0,0 +1,63 @@
+use ore_api::prelude::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+use steel::*;
+
+/// Closes a fully checkpointed miner account, and returns the rent to the authority.
+pub fn process_close_miner(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, automation_info, miner_info, treasury_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_CLAIMS) {
+        return Err(OreError::Paused.into());
+    }
+    automation_info
+        .is_empty()?
+        .has_seeds(
+            &[
+                AUTOMATION,
+                &config.mint.to_bytes(),
+                &signer_info.key.to_bytes(),
+            ],
+            &ore_api::ID,
+        )?;
+    miner_info.is_writable()?.has_seeds(
+        &[MINER, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+        &ore_api::ID,
+    )?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&ore_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Settle any outstanding refining rewards.
+    miner.update_rewards(treasury)?;
+
+    // Ensure the miner has nothing left to checkpoint or claim.
+    if miner.checkpoint_id != miner.round_id
+        || miner.rewards_sol > 0
+        || miner.rewards_ore > 0
+        || miner.refined_ore > 0
+    {
+        return Err(OreError::MinerNotClosable.into());
+    }
+
+    // Close the account.
+    sol_log(
+        &format!(
+            "Closing miner, returning {} SOL",
+            lamports_to_sol(miner_info.lamports())
+        )
+        .as_str(),
+    );
+    miner_info.close(signer_info)?;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+/// Transfers a pending stake withdrawal to the staker once its cooldown has elapsed.
+pub fn process_complete_withdraw(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, mint_info, recipient_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program, board_info, ore_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_WITHDRAWALS) {
+        return Err(OreError::Paused.into());
+    }
+    mint_info.has_address(&config.mint)?.as_mint()?;
+    recipient_info.is_writable()?;
+    stake_info.has_seeds(
+        &[STAKE, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+        &ore_api::ID,
+    )?;
+    let stake = stake_info
+        .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut(|s| s.authority == *signer_info.key)?;
+    stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    let treasury = treasury_info.as_account::<Treasury>(&ore_api::ID)?;
+    treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Open recipient token account.
+    if recipient_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            signer_info,
+            recipient_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        recipient_info.as_associated_token_account(&signer_info.key, &mint_info.key)?;
+    }
+
+    // Release the pending withdrawal.
+    let amount = stake.complete_withdraw(&clock)?;
+
+    // Transfer ORE to recipient.
+    transfer_signed(
+        stake_info,
+        stake_tokens_info,
+        recipient_info,
+        token_program,
+        amount,
+        &[STAKE, &config.mint.to_bytes(), &stake.authority.to_bytes()],
+    )?;
+
+    // Log withdraw.
+    sol_log(
+        &format!(
+            "Withdrawing {} ORE",
+            amount_to_ui_amount(amount, TOKEN_DECIMALS)
+        )
+        .as_str(),
+    );
+
+    // Safety check.
+    let stake_tokens =
+        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    if stake_tokens.amount() < stake.balance + stake.pending_withdraw {
+        return Err(OreError::StakeBalanceMismatch.into());
+    }
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        StakeEvent {
+            disc: OreEvent::Stake as u64,
+            authority: stake.authority,
+            kind: StakeKind::Withdraw as u64,
+            amount,
+            balance: stake.balance,
+            total_staked: treasury.total_staked,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
@@ -5,25 +5,36 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
-/// Compounds yield from the staking contract.
+/// Compounds yield from the staking contract. Anyone may compound a stake account once per day, and
+/// is paid from the stake's compound fee reserve. The stake authority may be passed as an optional
+/// trailing account, and defaults to the signer.
 pub fn process_compound_yield(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, config_info, mint_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program] =
+    let [signer_info, config_info, mint_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, board_info, ore_program, authority_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    let authority_info = authority_accounts.first().unwrap_or(signer_info);
     signer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_STAKING) {
+        return Err(OreError::Paused.into());
+    }
     mint_info.has_address(&config.mint)?.as_mint()?;
     stake_info.has_seeds(
-        &[STAKE, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+        &[
+            STAKE,
+            &config.mint.to_bytes(),
+            &authority_info.key.to_bytes(),
+        ],
         &ore_api::ID,
     )?;
     let stake = stake_info
         .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut(|s| s.authority == *authority_info.key)?
         .assert_mut(|s| s.compound_fee_reserve >= COMPOUND_FEE_PER_TRANSACTION)?
         .assert_mut(|s| s.last_claim_at + ONE_DAY < clock.unix_timestamp)?;
     stake_tokens_info
@@ -36,12 +47,14 @@ pub fn process_compound_yield(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Pro
         .as_associated_token_account(&treasury_info.key, &mint_info.key)?;
     system_program.is_program(&system_program::ID)?;
     token_program.is_program(&spl_token::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
 
     // Claim yield from stake account.
-    let amount = stake.claim(u64::MAX, &clock, treasury);
+    let amount = stake.claim(u64::MAX, &clock, treasury)?;
 
     // Deposit into stake account.
-    let amount = stake.deposit(amount, &clock, treasury, &treasury_tokens);
+    let amount = stake.deposit(amount, &clock, treasury, &treasury_tokens)?;
 
     // Transfer ORE from treasury to stake.
     transfer_signed(
@@ -66,5 +79,21 @@ pub fn process_compound_yield(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Pro
         .as_str(),
     );
 
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        StakeEvent {
+            disc: OreEvent::Stake as u64,
+            authority: stake.authority,
+            kind: StakeKind::Compound as u64,
+            amount,
+            balance: stake.balance,
+            total_staked: treasury.total_staked,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
     Ok(())
 }
@@ -3,15 +3,44 @@ use ore_api::prelude::*;
 use solana_program::{keccak::hashv, log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
+use crate::upgrade_automation;
+
 /// Deploys capital to prospect on a square.
 pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = Deploy::try_from_bytes(data)?;
-    let mut amount = u64::from_le_bytes(args.amount);
+    let amount = u64::from_le_bytes(args.amount);
     let mask = u32::from_le_bytes(args.squares);
 
+    deploy_squares(
+        accounts,
+        mask_amounts(mask as u64, amount),
+        OreEvent::Deploy,
+    )
+}
+
+/// Returns the per-square amounts for deploying the same amount to each square selected by a mask.
+pub fn mask_amounts(mask: u64, amount: u64) -> [u64; MAX_SQUARES] {
+    let mut amounts = [0; MAX_SQUARES];
+    for (i, square_amount) in amounts.iter_mut().enumerate() {
+        if mask & (1 << i) != 0 {
+            *square_amount = amount;
+        }
+    }
+    amounts
+}
+
+/// Deploys the given amount of capital to each square, and emits the given deploy event.
+pub fn deploy_squares(
+    accounts: &[AccountInfo<'_>],
+    mut amounts: [u64; MAX_SQUARES],
+    event: OreEvent,
+) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
+    if accounts.len() < 9 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
     let (ore_accounts, entropy_accounts) = accounts.split_at(9);
     sol_log(&format!("Ore accounts: {:?}", ore_accounts.len()).to_string());
     sol_log(&format!("Entropy accounts: {:?}", entropy_accounts.len()).to_string());
@@ -24,6 +53,9 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     authority_info.is_writable()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_MINING) {
+        return Err(OreError::Paused.into());
+    }
     automation_info.is_writable()?.has_seeds(
         &[
             AUTOMATION,
@@ -35,7 +67,10 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
     let board = board_info
         .as_account_mut::<Board>(&ore_api::ID)?
-        .assert_mut(|b| clock.slot >= b.start_slot && clock.slot < b.end_slot)?;
+        .assert_mut_err(
+            |b| clock.slot >= b.start_slot && clock.slot < b.end_slot,
+            OreError::RoundNotActive.into(),
+        )?;
     let round = round_info
         .as_account_mut::<Round>(&ore_api::ID)?
         .assert_mut(|r| r.id == board.round_id)?;
@@ -57,76 +92,87 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     )?;
     system_program.is_program(&system_program::ID)?;
 
-    // Wait until first deploy to start round.
-    if board.end_slot == u64::MAX {
-        board.start_slot = clock.slot;
-        board.end_slot = board.start_slot + 150;
-        round.expires_at = board.end_slot + ONE_DAY_SLOTS;
-
-        // Bump var to the next value.
-        let [var_info, entropy_program] = entropy_accounts else {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        };
-        var_info
-            .has_address(&VAR_ADDRESS)?
-            .as_account::<Var>(&entropy_api::ID)?
-            .assert(|v| v.authority == *board_info.key)?;
-        entropy_program.is_program(&entropy_api::ID)?;
-
-        // Bump var to the next value.
-        invoke_signed(
-            &entropy_api::sdk::next(*board_info.key, *var_info.key, board.end_slot),
-            &[board_info.clone(), var_info.clone()],
-            &entropy_api::ID,
-            &[BOARD, &config.mint.to_bytes()],
-        )?;
-    }
-
     // Check if signer is the automation executor.
     let mut strategy = u64::MAX;
     let automation = if !automation_info.data_is_empty() {
+        let upgrade_rent = upgrade_automation(automation_info)?;
         let automation = automation_info
             .as_account_mut::<Automation>(&ore_api::ID)?
             .assert_mut(|a| a.executor == *signer_info.key || a.executor == EXECUTOR_ADDRESS)?
             .assert_mut(|a| a.authority == *authority_info.key)?;
+
+        // Close automation if its balance cannot cover the rent for the new layout.
+        if automation.balance < upgrade_rent {
+            automation_info.close(authority_info)?;
+            return Ok(());
+        }
+        automation.balance -= upgrade_rent;
         strategy = automation.strategy as u64;
         Some(automation)
     } else {
         None
     };
 
-    // Update amount and mask for automation.
-    let mut squares = [false; 25];
+    // Wait until first deploy to start round.
+    if board.end_slot == u64::MAX {
+        board.start_slot = clock.slot;
+        board.end_slot = board.start_slot + config.round_slots;
+        round.expires_at = board.end_slot + config.expiry_slots;
+        round.randomness = config.randomness;
+
+        // Bump var to the next value.
+        if round.randomness == RANDOMNESS_ENTROPY {
+            let [var_info, entropy_program] = entropy_accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            var_info
+                .has_address(&VAR_ADDRESS)?
+                .as_account::<Var>(&entropy_api::ID)?
+                .assert(|v| v.authority == *board_info.key)?;
+            entropy_program.is_program(&entropy_api::ID)?;
+
+            // Bump var to the next value.
+            invoke_signed(
+                &entropy_api::sdk::next(*board_info.key, *var_info.key, board.end_slot),
+                &[board_info.clone(), var_info.clone()],
+                &entropy_api::ID,
+                &[BOARD, &config.mint.to_bytes()],
+            )?;
+        }
+    }
+
+    // Update amounts for automation.
+    let num_squares = round.squares as usize;
     if let Some(automation) = &automation {
-        // Set amount and squares based on automation strategy.
-        match AutomationStrategy::from_u64(automation.strategy as u64) {
+        // Set amounts based on automation strategy.
+        let amount = automation.amount;
+        match AutomationStrategy::from_u64(automation.strategy as u64)? {
             AutomationStrategy::Preferred => {
                 // Preferred automation strategy. Use the miner authority's provided mask.
-                amount = automation.amount;
-                for i in 0..25 {
-                    squares[i] = (automation.mask & (1 << i)) != 0;
-                }
+                amounts = mask_amounts(automation.mask, amount);
             }
             AutomationStrategy::Random => {
                 // Random automation strategy. Generate a random mask based on number of squares user wants to deploy to.
-                amount = automation.amount;
-                let num_squares = ((automation.mask & 0xFF) as u64).min(25);
+                let count = (automation.mask & 0xFF).min(round.squares);
                 let r = hashv(&[&automation.authority.to_bytes(), &round.id.to_le_bytes()]).0;
-                squares = generate_random_mask(num_squares, &r);
+                amounts = generate_random_mask(count, num_squares, &r).map(|s| s as u64 * amount);
             }
             AutomationStrategy::Discretionary => {
-                // Discretionary automation strategy. Use the executor's provided mask.
-                amount = amount.min(automation.amount);
-                for i in 0..25 {
-                    squares[i] = (mask & (1 << i)) != 0;
-                }
+                // Discretionary automation strategy. Use the executor's provided amounts.
+                amounts = amounts.map(|a| a.min(amount));
+            }
+            AutomationStrategy::LeastCrowded => {
+                // Least crowded automation strategy. Deploy to the squares with the least SOL deployed.
+                let count = (automation.mask & 0xFF).min(round.squares);
+                amounts = generate_crowd_mask(count, num_squares, &round.deployed, false)
+                    .map(|s| s as u64 * amount);
+            }
+            AutomationStrategy::MostCrowded => {
+                // Most crowded automation strategy. Deploy to the squares with the most SOL deployed.
+                let count = (automation.mask & 0xFF).min(round.squares);
+                amounts = generate_crowd_mask(count, num_squares, &round.deployed, true)
+                    .map(|s| s as u64 * amount);
             }
-        }
-    } else {
-        // Convert provided 32-bit mask into array of 25 booleans, where each bit in the mask
-        // determines if that square index is selected (true) or not (false)
-        for i in 0..25 {
-            squares[i] = (mask & (1 << i)) != 0;
         }
     }
 
@@ -141,14 +187,16 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         )?;
         let miner = miner_info.as_account_mut::<Miner>(&ore_api::ID)?;
         miner.authority = *signer_info.key;
-        miner.deployed = [0; 25];
-        miner.cumulative = [0; 25];
+        miner.deployed = [0; MAX_SQUARES];
+        miner.cumulative = [0; MAX_SQUARES];
         miner.rewards_sol = 0;
         miner.rewards_ore = 0;
         miner.round_id = 0;
         miner.checkpoint_id = 0;
         miner.lifetime_rewards_sol = 0;
         miner.lifetime_rewards_ore = 0;
+        miner.lifetime_wins = 0;
+        miner.payout = Pubkey::default();
         miner
     } else {
         miner_info
@@ -165,32 +213,41 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     // Reset miner
     if miner.round_id != round.id {
         // Assert miner has checkpointed prior round.
-        assert!(
-            miner.checkpoint_id == miner.round_id,
-            "Miner has not checkpointed"
-        );
+        if miner.checkpoint_id != miner.round_id {
+            return Err(OreError::NotCheckpointed.into());
+        }
 
         // Reset miner for new round.
-        miner.deployed = [0; 25];
+        miner.deployed = [0; MAX_SQUARES];
         miner.cumulative = round.deployed;
         miner.round_id = round.id;
     }
 
+    // Close automation if any of its limits have been reached.
+    if let Some(automation) = &automation {
+        if automation.is_limit_reached(&clock, miner) {
+            sol_log("Automation limit reached");
+            automation_info.close(authority_info)?;
+            return Ok(());
+        }
+    }
+
     // Update total miners for round.
     let is_first_deploy = miner.deployed.iter().sum::<u64>() == 0;
 
     // Calculate all deployments.
     let mut total_amount = 0;
     let mut total_squares = 0;
-    let mut deployed_squares = [false; 25];
-    for (square_id, &should_deploy) in squares.iter().enumerate() {
+    let mut deployed = [0; MAX_SQUARES];
+    let mut mask = 0u64;
+    for (square_id, &amount) in amounts.iter().enumerate() {
         // Skip if square index is out of bounds.
-        if square_id > 24 {
+        if square_id >= num_squares {
             break;
         }
 
-        // Skip if square is not deployed to.
-        if !should_deploy {
+        // Skip if no SOL is deployed to this square.
+        if amount == 0 {
             continue;
         }
 
@@ -199,6 +256,18 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
             continue;
         }
 
+        // Exit early if automation does not have enough balance or spend limit for this square.
+        if let Some(automation) = &automation {
+            if total_amount + automation.fee + amount > automation.balance {
+                break;
+            }
+            if automation.max_spend > 0
+                && automation.total_spent + total_amount + amount > automation.max_spend
+            {
+                break;
+            }
+        }
+
         // Record cumulative amount.
         miner.cumulative[square_id] = round.deployed[square_id];
 
@@ -213,14 +282,8 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         // Update totals.
         total_amount += amount;
         total_squares += 1;
-        deployed_squares[square_id] = true;
-
-        // Exit early if automation does not have enough balance for another square.
-        if let Some(automation) = &automation {
-            if total_amount + automation.fee + amount > automation.balance {
-                break;
-            }
-        }
+        deployed[square_id] = amount;
+        mask |= 1 << square_id;
     }
 
     // Update total miners for round.
@@ -251,38 +314,60 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         automation_info.send(total_amount, &round_info);
         automation_info.send(automation_fee, &signer_info);
 
-        // Close automation if balance is less than what's required to deploy 1 square.
-        if automation.balance < automation.amount + automation.fee {
+        // Update automation limits and stats.
+        automation.total_spent += total_amount;
+        automation.lifetime_deployed += total_amount;
+        automation.lifetime_fees += automation_fee;
+        if is_first_deploy && total_amount > 0 {
+            automation.rounds_played += 1;
+            automation.lifetime_rounds += 1;
+            automation.last_round_id = round.id;
+        }
+        automation.last_wins = miner.lifetime_wins;
+
+        // Close automation if balance is less than what's required to deploy 1 square, or a limit was reached.
+        if automation.balance < automation.amount + automation.fee
+            || automation.is_limit_reached(&clock, miner)
+        {
             automation_info.close(authority_info)?;
         }
     } else {
         round_info.collect(total_amount, &signer_info)?;
     }
 
-    // Rebuild the mask from the deployed squares.
-    let mut deployed_mask = 0;
-    for (square_id, &deployed) in deployed_squares.iter().enumerate() {
-        if deployed {
-            deployed_mask |= 1 << square_id;
-        }
-    }
-
     // Log the deploy event.
-    program_log(
-        config.mint,
-        &[board_info.clone(), ore_program.clone()],
-        DeployEvent {
-            disc: 2,
+    let event = match event {
+        OreEvent::DeployWeighted => DeployWeightedEvent {
+            disc: OreEvent::DeployWeighted as u64,
             authority: miner.authority,
-            amount,
-            mask: deployed_mask as u64,
+            amounts: deployed,
+            mask,
+            round_id: round.id,
+            signer: *signer_info.key,
+            total_amount,
+            total_squares,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes()
+        .to_vec(),
+        _ => DeployEvent {
+            disc: OreEvent::Deploy as u64,
+            authority: miner.authority,
+            amount: deployed.iter().copied().max().unwrap_or(0),
+            mask,
             round_id: round.id,
             signer: *signer_info.key,
             strategy,
             total_squares,
             ts: clock.unix_timestamp,
         }
-        .to_bytes(),
+        .to_bytes()
+        .to_vec(),
+    };
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        &event,
     )?;
 
     // Log
@@ -290,7 +375,7 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         &format!(
             "Round #{}: deploying {} SOL to {} squares",
             round.id,
-            lamports_to_sol(amount),
+            lamports_to_sol(total_amount),
             total_squares,
         )
         .as_str(),
@@ -299,13 +384,15 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     Ok(())
 }
 
-fn generate_random_mask(num_squares: u64, r: &[u8]) -> [bool; 25] {
-    let mut new_mask = [false; 25];
+fn generate_random_mask(count: u64, num_squares: usize, r: &[u8; 32]) -> [bool; MAX_SQUARES] {
+    // Extend the seed so there is one random byte per square.
+    let r2 = hashv(&[r.as_slice()]).0;
+    let mut new_mask = [false; MAX_SQUARES];
     let mut selected = 0;
-    for i in 0..25 {
-        let rand_byte = r[i];
-        let remaining_needed = num_squares as u64 - selected as u64;
-        let remaining_positions = 25 - i;
+    for i in 0..num_squares {
+        let rand_byte = if i < 32 { r[i] } else { r2[i - 32] };
+        let remaining_needed = count - selected as u64;
+        let remaining_positions = num_squares - i;
         if remaining_needed > 0
             && (rand_byte as u64) * (remaining_positions as u64) < (remaining_needed * 256)
         {
@@ -315,3 +402,65 @@ fn generate_random_mask(num_squares: u64, r: &[u8]) -> [bool; 25] {
     }
     new_mask
 }
+
+fn generate_crowd_mask(
+    count: u64,
+    num_squares: usize,
+    deployed: &[u64; MAX_SQUARES],
+    most: bool,
+) -> [bool; MAX_SQUARES] {
+    // Rank squares by amount deployed, breaking ties by the lower square index.
+    let mut order = (0..num_squares).collect::<Vec<usize>>();
+    if most {
+        order.sort_by_key(|&i| (std::cmp::Reverse(deployed[i]), i));
+    } else {
+        order.sort_by_key(|&i| (deployed[i], i));
+    }
+    let mut new_mask = [false; MAX_SQUARES];
+    for &i in order.iter().take(count as usize) {
+        new_mask[i] = true;
+    }
+    new_mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selected(mask: &[bool; MAX_SQUARES]) -> Vec<usize> {
+        (0..MAX_SQUARES).filter(|&i| mask[i]).collect()
+    }
+
+    #[test]
+    fn test_generate_crowd_mask_least() {
+        let mut deployed = [0; MAX_SQUARES];
+        deployed[..5].copy_from_slice(&[30, 10, 20, 10, 50]);
+        let mask = generate_crowd_mask(2, 5, &deployed, false);
+        assert_eq!(selected(&mask), vec![1, 3]);
+        let mask = generate_crowd_mask(3, 5, &deployed, false);
+        assert_eq!(selected(&mask), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_generate_crowd_mask_most() {
+        let mut deployed = [0; MAX_SQUARES];
+        deployed[..5].copy_from_slice(&[30, 50, 20, 50, 10]);
+        let mask = generate_crowd_mask(2, 5, &deployed, true);
+        assert_eq!(selected(&mask), vec![1, 3]);
+        let mask = generate_crowd_mask(3, 5, &deployed, true);
+        assert_eq!(selected(&mask), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_generate_crowd_mask_bounds() {
+        // Squares outside the board are never selected, even if they rank higher.
+        let mut deployed = [0; MAX_SQUARES];
+        deployed[10] = 100;
+        let mask = generate_crowd_mask(1, 5, &deployed, true);
+        assert_eq!(selected(&mask), vec![0]);
+        let mask = generate_crowd_mask(u64::MAX, 5, &deployed, true);
+        assert_eq!(selected(&mask), vec![0, 1, 2, 3, 4]);
+        let mask = generate_crowd_mask(0, 5, &deployed, false);
+        assert!(selected(&mask).is_empty());
+    }
+}
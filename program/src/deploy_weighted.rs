@@ -0,0 +1,21 @@
+use ore_api::prelude::*;
+use steel::*;
+
+use crate::deploy_squares;
+
+/// Deploys a different amount of capital to each of the provided squares.
+pub fn process_deploy_weighted(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let (_args, entries) = DeployWeighted::try_from_bytes_with_entries(data)?;
+
+    // Collect per-square amounts. If a square is listed more than once, the first entry is used.
+    let mut amounts = [0; MAX_SQUARES];
+    for entry in entries {
+        let square_id = entry.square as usize;
+        if square_id < MAX_SQUARES && amounts[square_id] == 0 {
+            amounts[square_id] = u64::from_le_bytes(entry.amount);
+        }
+    }
+
+    deploy_squares(accounts, amounts, OreEvent::DeployWeighted)
+}
@@ -0,0 +1,14 @@
+use ore_api::prelude::*;
+use steel::*;
+
+use crate::{deploy_squares, mask_amounts};
+
+/// Deploys capital to prospect on squares selected by a 64-bit mask.
+pub fn process_deploy_wide(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = DeployWide::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+    let mask = u64::from_le_bytes(args.squares);
+
+    deploy_squares(accounts, mask_amounts(mask, amount), OreEvent::Deploy)
+}
@@ -12,7 +12,7 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
 
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, payer_info, config_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+    let [signer_info, payer_info, config_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program, board_info, ore_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -21,6 +21,9 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     payer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_STAKING) {
+        return Err(OreError::Paused.into());
+    }
     mint_info.has_address(&config.mint)?.as_mint()?;
     let sender = sender_info
         .is_writable()?
@@ -31,6 +34,8 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     system_program.is_program(&system_program::ID)?;
     token_program.is_program(&spl_token::ID)?;
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
 
     // Open stake account.
     let stake = if stake_info.data_is_empty() {
@@ -42,20 +47,7 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
             &[STAKE, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
         )?;
         let stake = stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
-        stake.authority = *signer_info.key;
-        stake.balance = 0;
-        stake.buffer_a = 0;
-        stake.buffer_b = 0;
-        stake.buffer_c = 0;
-        stake.buffer_d = 0;
-        stake.compound_fee_reserve = 0;
-        stake.last_claim_at = 0;
-        stake.last_deposit_at = 0;
-        stake.last_withdraw_at = 0;
-        stake.rewards_factor = treasury.stake_rewards_factor;
-        stake.rewards = 0;
-        stake.lifetime_rewards = 0;
-        stake.buffer_f = 0;
+        stake.init(*signer_info.key, treasury);
         stake
     } else {
         stake_info.has_seeds(
@@ -86,7 +78,7 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     // assert!(stake.is_seeker == 1, "Only seekers can deposit stake");
 
     // Deposit into stake account.
-    let amount = stake.deposit(amount, &clock, treasury, &sender);
+    let amount = stake.deposit(amount, &clock, treasury, &sender)?;
 
     // Transfer ORE to treasury.
     transfer(
@@ -113,7 +105,25 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     // Safety check.
     let stake_tokens =
         stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
-    assert!(stake_tokens.amount() >= stake.balance);
+    if stake_tokens.amount() < stake.balance + stake.pending_withdraw {
+        return Err(OreError::StakeBalanceMismatch.into());
+    }
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        StakeEvent {
+            disc: OreEvent::Stake as u64,
+            authority: stake.authority,
+            kind: StakeKind::Deposit as u64,
+            amount,
+            balance: stake.balance,
+            total_staked: treasury.total_staked,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,149 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+/// Deposits ORE into the liquid staking pool in exchange for receipt tokens.
+pub fn process_deposit_liquid(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = DepositLiquid::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, mint_info, sender_info, receipt_mint_info, recipient_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, board_info, ore_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_STAKING) {
+        return Err(OreError::Paused.into());
+    }
+    mint_info.has_address(&config.mint)?.as_mint()?;
+    let sender = sender_info
+        .is_writable()?
+        .as_associated_token_account(&signer_info.key, &config.mint)?;
+    let receipt_mint = receipt_mint_info
+        .is_writable()?
+        .has_seeds(&[RECEIPT, &config.mint.to_bytes()], &ore_api::ID)?
+        .as_mint()?;
+    recipient_info.is_writable()?;
+    stake_info.has_seeds(
+        &[
+            STAKE,
+            &config.mint.to_bytes(),
+            &receipt_mint_info.key.to_bytes(),
+        ],
+        &ore_api::ID,
+    )?;
+    let stake = stake_info
+        .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut(|s| s.authority == *receipt_mint_info.key)?;
+    stake_tokens_info
+        .is_writable()?
+        .as_associated_token_account(stake_info.key, mint_info.key)?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
+    let treasury_tokens = treasury_tokens_info
+        .is_writable()?
+        .as_associated_token_account(&treasury_info.key, &mint_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Open recipient token account.
+    if recipient_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            signer_info,
+            recipient_info,
+            receipt_mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        recipient_info.as_associated_token_account(&signer_info.key, &receipt_mint_info.key)?;
+    }
+
+    // Compound pool yield, so the exchange rate reflects all accrued rewards.
+    let yield_amount = stake.claim(u64::MAX, &clock, treasury)?;
+    let yield_amount = stake.deposit(yield_amount, &clock, treasury, &treasury_tokens)?;
+    transfer_signed(
+        treasury_info,
+        treasury_tokens_info,
+        stake_tokens_info,
+        token_program,
+        yield_amount,
+        &[TREASURY, &config.mint.to_bytes()],
+    )?;
+
+    // Calculate receipt tokens to mint.
+    let amount = sender.amount().min(amount);
+    let shares = stake.liquid_shares(amount, receipt_mint.supply());
+    if shares == 0 {
+        return Err(OreError::AmountTooSmall.into());
+    }
+
+    // Deposit into pool stake account.
+    let amount = stake.deposit(amount, &clock, treasury, &sender)?;
+
+    // Transfer ORE to pool.
+    transfer(
+        signer_info,
+        sender_info,
+        stake_tokens_info,
+        token_program,
+        amount,
+    )?;
+
+    // Mint receipt tokens.
+    mint_to_signed(
+        receipt_mint_info,
+        recipient_info,
+        receipt_mint_info,
+        token_program,
+        shares,
+        &[RECEIPT, &config.mint.to_bytes()],
+    )?;
+
+    // Log deposit.
+    sol_log(
+        &format!(
+            "Depositing {} ORE for {} receipt tokens",
+            amount_to_ui_amount(amount, TOKEN_DECIMALS),
+            amount_to_ui_amount(shares, TOKEN_DECIMALS)
+        )
+        .as_str(),
+    );
+
+    // Safety check.
+    let stake_tokens =
+        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    if stake_tokens.amount() < stake.balance {
+        return Err(OreError::StakeBalanceMismatch.into());
+    }
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        StakeEvent {
+            disc: OreEvent::Stake as u64,
+            authority: *signer_info.key,
+            kind: StakeKind::DepositLiquid as u64,
+            amount,
+            balance: stake.balance,
+            total_staked: treasury.total_staked,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
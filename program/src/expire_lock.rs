@@ -0,0 +1,46 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Drops the boost of an elapsed stake lock so it stops counting toward the total staked weight.
+/// Anyone may call this on any stake account.
+pub fn process_expire_lock(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, stake_info, treasury_info, board_info, ore_program] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    let stake = stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
+    stake_info.has_seeds(
+        &[STAKE, &config.mint.to_bytes(), &stake.authority.to_bytes()],
+        &ore_api::ID,
+    )?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Expire lock.
+    stake.unlock(&clock, treasury)?;
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        StakeEvent {
+            disc: OreEvent::Stake as u64,
+            authority: stake.authority,
+            kind: StakeKind::Unlock as u64,
+            amount: stake.balance,
+            balance: stake.balance,
+            total_staked: treasury.total_staked,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
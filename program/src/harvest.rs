@@ -0,0 +1,231 @@
+use ore_api::prelude::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+use crate::checkpoint_miner;
+
+/// Checkpoints a miner, claims their SOL and ORE rewards, and restakes a share of the ORE.
+pub fn process_harvest(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Harvest::try_from_bytes(data)?;
+    let restake_bps = u64::from_le_bytes(args.restake_bps);
+    if restake_bps > DENOMINATOR_BPS {
+        return Err(OreError::InvalidBasisPoints.into());
+    }
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, board_info, miner_info, round_info, mint_info, payout_info, recipient_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, ore_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_CLAIMS) || (restake_bps > 0 && config.is_paused(PAUSE_STAKING)) {
+        return Err(OreError::Paused.into());
+    }
+    let board = board_info.as_account::<Board>(&ore_api::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    miner_info.has_seeds(
+        &[MINER, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+        &ore_api::ID,
+    )?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&ore_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?;
+    mint_info.has_address(&config.mint)?.as_mint()?;
+    payout_info
+        .is_writable()?
+        .has_address(&miner.payout_address())?;
+    recipient_info.is_writable()?;
+    stake_info.is_writable()?;
+    stake_tokens_info.is_writable()?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
+    treasury_tokens_info.as_associated_token_account(treasury_info.key, mint_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Checkpoint miner.
+    if let Some(event) = checkpoint_miner(
+        signer_info,
+        miner_info,
+        round_info,
+        config,
+        board,
+        miner,
+        treasury,
+    )? {
+        program_log(
+            config.mint,
+            &[board_info.clone(), ore_program.clone()],
+            event.to_bytes(),
+        )?;
+    }
+
+    // Claim SOL.
+    let amount_sol = miner.claim_sol(&clock);
+    if amount_sol > 0 {
+        sol_log(&format!("Claiming {} SOL", lamports_to_sol(amount_sol)).as_str());
+        miner_info.send(amount_sol, payout_info);
+        program_log(
+            config.mint,
+            &[board_info.clone(), ore_program.clone()],
+            ClaimEvent {
+                disc: OreEvent::Claim as u64,
+                authority: *signer_info.key,
+                kind: ClaimKind::Sol as u64,
+                amount: amount_sol,
+                recipient: *payout_info.key,
+                ts: clock.unix_timestamp,
+            }
+            .to_bytes(),
+        )?;
+    }
+
+    // Claim ORE.
+    let amount_ore = miner.claim_ore(&clock, treasury)?;
+    if amount_ore == 0 {
+        return Ok(());
+    }
+    let restake_amount =
+        ((amount_ore as u128 * restake_bps as u128) / DENOMINATOR_BPS as u128) as u64;
+    let payout_amount = amount_ore - restake_amount;
+
+    // Transfer unstaked rewards to recipient.
+    if payout_amount > 0 {
+        if recipient_info.data_is_empty() {
+            create_associated_token_account(
+                signer_info,
+                payout_info,
+                recipient_info,
+                mint_info,
+                system_program,
+                token_program,
+                associated_token_program,
+            )?;
+        } else {
+            recipient_info.as_associated_token_account(payout_info.key, mint_info.key)?;
+        }
+        sol_log(
+            &format!(
+                "Claiming {} ORE",
+                amount_to_ui_amount(payout_amount, TOKEN_DECIMALS)
+            )
+            .as_str(),
+        );
+        transfer_signed(
+            treasury_info,
+            treasury_tokens_info,
+            recipient_info,
+            token_program,
+            payout_amount,
+            &[TREASURY, &config.mint.to_bytes()],
+        )?;
+        program_log(
+            config.mint,
+            &[board_info.clone(), ore_program.clone()],
+            ClaimEvent {
+                disc: OreEvent::Claim as u64,
+                authority: *signer_info.key,
+                kind: ClaimKind::Ore as u64,
+                amount: payout_amount,
+                recipient: *recipient_info.key,
+                ts: clock.unix_timestamp,
+            }
+            .to_bytes(),
+        )?;
+    }
+
+    // Exit early if nothing to restake.
+    if restake_amount == 0 {
+        return Ok(());
+    }
+
+    // Open stake account.
+    let stake = if stake_info.data_is_empty() {
+        create_program_account::<Stake>(
+            stake_info,
+            system_program,
+            signer_info,
+            &ore_api::ID,
+            &[STAKE, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+        )?;
+        let stake = stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
+        stake.init(*signer_info.key, treasury);
+        stake
+    } else {
+        stake_info.has_seeds(
+            &[STAKE, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+            &ore_api::ID,
+        )?;
+        stake_info
+            .as_account_mut::<Stake>(&ore_api::ID)?
+            .assert_mut(|s| s.authority == *signer_info.key)?
+    };
+
+    // Create stake tokens account.
+    if stake_tokens_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            stake_info,
+            stake_tokens_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    }
+
+    // Deposit restaked rewards into stake account.
+    let treasury_tokens =
+        treasury_tokens_info.as_associated_token_account(treasury_info.key, mint_info.key)?;
+    let restake_amount = stake.deposit(restake_amount, &clock, treasury, treasury_tokens)?;
+    sol_log(
+        &format!(
+            "Restaking {} ORE",
+            amount_to_ui_amount(restake_amount, TOKEN_DECIMALS)
+        )
+        .as_str(),
+    );
+    transfer_signed(
+        treasury_info,
+        treasury_tokens_info,
+        stake_tokens_info,
+        token_program,
+        restake_amount,
+        &[TREASURY, &config.mint.to_bytes()],
+    )?;
+
+    // Safety check.
+    let stake_tokens =
+        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    if stake_tokens.amount() < stake.balance + stake.pending_withdraw {
+        return Err(OreError::StakeBalanceMismatch.into());
+    }
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        StakeEvent {
+            disc: OreEvent::Stake as u64,
+            authority: stake.authority,
+            kind: StakeKind::Deposit as u64,
+            amount: restake_amount,
+            balance: stake.balance,
+            total_staked: treasury.total_staked,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
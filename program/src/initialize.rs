@@ -0,0 +1,153 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Initializes the program accounts for a new mint.
+pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Initialize::try_from_bytes(data)?;
+    let reward_per_round = u64::from_le_bytes(args.reward_per_round);
+    let max_supply = u64::from_le_bytes(args.max_supply);
+    let motherlode_bps = u64::from_le_bytes(args.motherlode_bps);
+    let stake_bps = u64::from_le_bytes(args.stake_bps);
+    let squares = u64::from_le_bytes(args.squares);
+    if motherlode_bps > DENOMINATOR_BPS || stake_bps > DENOMINATOR_BPS {
+        return Err(OreError::InvalidBasisPoints.into());
+    }
+    if squares == 0 || squares > MAX_SQUARES as u64 {
+        return Err(OreError::InvalidGridSize.into());
+    }
+
+    // Load accounts.
+    let [signer_info, board_info, config_info, mint_info, round_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?.has_address(&ADMIN_ADDRESS)?;
+    mint_info.as_mint()?;
+    let mint = *mint_info.key;
+    board_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[BOARD, &mint.to_bytes()], &ore_api::ID)?;
+    config_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[CONFIG, &mint.to_bytes()], &ore_api::ID)?;
+    round_info.is_empty()?.is_writable()?.has_seeds(
+        &[ROUND, &mint.to_bytes(), &0u64.to_le_bytes()],
+        &ore_api::ID,
+    )?;
+    treasury_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[TREASURY, &mint.to_bytes()], &ore_api::ID)?;
+    treasury_tokens_info.is_writable()?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Open config account.
+    create_program_account::<Config>(
+        config_info,
+        system_program,
+        signer_info,
+        &ore_api::ID,
+        &[CONFIG, &mint.to_bytes()],
+    )?;
+    let config = config_info.as_account_mut::<Config>(&ore_api::ID)?;
+    config.admin = *signer_info.key;
+    config.mint = mint;
+    config.reward_per_round = reward_per_round;
+    config.max_supply = max_supply;
+    config.motherlode_bps = motherlode_bps;
+    config.stake_bps = stake_bps;
+    config.fee_collector = ADMIN_FEE_COLLECTOR;
+    config.fee_rate = ADMIN_FEE;
+    config.pending_admin = Pubkey::default();
+    config.paused = 0;
+    config.round_slots = ROUND_SLOTS;
+    config.intermission_slots = INTERMISSION_SLOTS;
+    config.expiry_slots = EXPIRY_SLOTS;
+    config.abort_slots = ABORT_SLOTS;
+    config.randomness = RANDOMNESS_ENTROPY;
+    config.withdraw_cooldown = 0;
+    config.squares = squares;
+    config.reserved = [0; 8];
+
+    // Open board account.
+    create_program_account::<Board>(
+        board_info,
+        system_program,
+        signer_info,
+        &ore_api::ID,
+        &[BOARD, &mint.to_bytes()],
+    )?;
+    let board = board_info.as_account_mut::<Board>(&ore_api::ID)?;
+    board.round_id = 0;
+    board.start_slot = 0;
+    board.end_slot = u64::MAX; // Set to max, to indicate board is waiting for first deploy to begin.
+    board.epoch_id = 0;
+
+    // Open first round account.
+    create_program_account::<Round>(
+        round_info,
+        system_program,
+        signer_info,
+        &ore_api::ID,
+        &[ROUND, &mint.to_bytes(), &0u64.to_le_bytes()],
+    )?;
+    let round = round_info.as_account_mut::<Round>(&ore_api::ID)?;
+    round.id = 0;
+    round.deployed = [0; MAX_SQUARES];
+    round.slot_hash = [0; 32];
+    round.count = [0; MAX_SQUARES];
+    round.expires_at = u64::MAX; // Set to max, to indicate round is waiting for first deploy to begin.
+    round.rent_payer = *signer_info.key;
+    round.motherlode = 0;
+    round.top_miner = Pubkey::default();
+    round.top_miner_reward = 0;
+    round.total_deployed = 0;
+    round.total_miners = 0;
+    round.total_vaulted = 0;
+    round.total_winnings = 0;
+    round.squares = squares;
+    round.fee_rate = 0;
+    round.randomness = RANDOMNESS_ENTROPY;
+
+    // Open treasury account.
+    create_program_account::<Treasury>(
+        treasury_info,
+        system_program,
+        signer_info,
+        &ore_api::ID,
+        &[TREASURY, &mint.to_bytes()],
+    )?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury.balance = 0;
+    treasury.buffer_a = 0;
+    treasury.motherlode = 0;
+    treasury.miner_rewards_factor = Numeric::ZERO;
+    treasury.stake_rewards_factor = Numeric::ZERO;
+    treasury.buffer_b = 0;
+    treasury.total_refined = 0;
+    treasury.total_staked = 0;
+    treasury.total_unclaimed = 0;
+
+    // Open treasury tokens account.
+    if treasury_tokens_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            treasury_info,
+            treasury_tokens_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        treasury_tokens_info.as_associated_token_account(treasury_info.key, mint_info.key)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use ore_api::prelude::*;
+use solana_program::{program_pack::Pack, rent::Rent, system_instruction};
+use spl_token::state::Mint;
+use steel::*;
+
+/// Creates the liquid staking receipt mint and the stake account backing it.
+pub fn process_initialize_receipt(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, mint_info, receipt_mint_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?.assert_err(
+        |c| c.admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    mint_info.has_address(&config.mint)?.as_mint()?;
+    receipt_mint_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[RECEIPT, &config.mint.to_bytes()], &ore_api::ID)?;
+    stake_info.is_empty()?.is_writable()?.has_seeds(
+        &[
+            STAKE,
+            &config.mint.to_bytes(),
+            &receipt_mint_info.key.to_bytes(),
+        ],
+        &ore_api::ID,
+    )?;
+    stake_tokens_info.is_writable()?;
+    let treasury = treasury_info.as_account::<Treasury>(&ore_api::ID)?;
+    treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Create receipt mint. The mint is its own authority. If the address was pre-funded, top up
+    // the rent and allocate it in place, since create_account would fail.
+    let rent = Rent::get()?.minimum_balance(Mint::LEN);
+    if receipt_mint_info.lamports() == 0 {
+        invoke_signed(
+            &system_instruction::create_account(
+                signer_info.key,
+                receipt_mint_info.key,
+                rent,
+                Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            &[
+                signer_info.clone(),
+                receipt_mint_info.clone(),
+                system_program.clone(),
+            ],
+            &ore_api::ID,
+            &[RECEIPT, &config.mint.to_bytes()],
+        )?;
+    } else {
+        let shortfall = rent.saturating_sub(receipt_mint_info.lamports());
+        if shortfall > 0 {
+            receipt_mint_info.collect(shortfall, signer_info)?;
+        }
+        invoke_signed(
+            &system_instruction::allocate(receipt_mint_info.key, Mint::LEN as u64),
+            &[receipt_mint_info.clone(), system_program.clone()],
+            &ore_api::ID,
+            &[RECEIPT, &config.mint.to_bytes()],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(receipt_mint_info.key, &spl_token::ID),
+            &[receipt_mint_info.clone(), system_program.clone()],
+            &ore_api::ID,
+            &[RECEIPT, &config.mint.to_bytes()],
+        )?;
+    }
+    solana_program::program::invoke(
+        &spl_token::instruction::initialize_mint2(
+            &spl_token::ID,
+            receipt_mint_info.key,
+            receipt_mint_info.key,
+            None,
+            TOKEN_DECIMALS,
+        )?,
+        &[receipt_mint_info.clone()],
+    )?;
+
+    // Open stake account backing the receipt.
+    create_program_account::<Stake>(
+        stake_info,
+        system_program,
+        signer_info,
+        &ore_api::ID,
+        &[
+            STAKE,
+            &config.mint.to_bytes(),
+            &receipt_mint_info.key.to_bytes(),
+        ],
+    )?;
+    let stake = stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
+    stake.init(*receipt_mint_info.key, treasury);
+
+    // Create stake tokens account.
+    if stake_tokens_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            stake_info,
+            stake_tokens_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    }
+
+    Ok(())
+}
@@ -1,43 +1,95 @@
+mod abort;
+mod accept_admin;
 mod automate;
 mod bury;
 mod buyback;
+mod cancel_admin_proposal;
 mod checkpoint;
+mod checkpoint_many;
 mod claim_ore;
 mod claim_sol;
 mod claim_yield;
 mod close;
+mod close_miner;
+mod complete_withdraw;
 mod compound_yield;
 mod deploy;
+mod deploy_weighted;
+mod deploy_wide;
 mod deposit;
+mod deposit_liquid;
+mod expire_lock;
+mod harvest;
+mod initialize;
 mod initialize_lp_pool;
+mod initialize_receipt;
 mod liq;
+mod lock_stake;
 mod log;
+mod migrate_config;
+mod migrate_miner;
+mod migrate_round;
 mod new_var;
+mod propose_admin;
 mod reload_sol;
+mod request_withdraw;
 mod reset;
-mod set_admin;
+mod set_automation_limits;
+mod set_config;
+mod set_fee_collector;
+mod set_fee_rate;
+mod set_pause;
+mod set_payout;
+mod transfer_stake;
 mod withdraw;
+mod withdraw_liquid;
 mod wrap;
 
+use abort::*;
+use accept_admin::*;
 use automate::*;
 use bury::*;
 use buyback::*;
+use cancel_admin_proposal::*;
 use checkpoint::*;
+use checkpoint_many::*;
 use claim_ore::*;
 use claim_sol::*;
 use claim_yield::*;
 use close::*;
+use close_miner::*;
+use complete_withdraw::*;
 use compound_yield::*;
 use deploy::*;
+use deploy_weighted::*;
+use deploy_wide::*;
 use deposit::*;
+use deposit_liquid::*;
+use expire_lock::*;
+use harvest::*;
+use initialize::*;
 use initialize_lp_pool::*;
+use initialize_receipt::*;
 use liq::*;
+use lock_stake::*;
 use log::*;
+use migrate_config::*;
+use migrate_miner::*;
+use migrate_round::*;
 use new_var::*;
+use propose_admin::*;
 use reload_sol::*;
+use request_withdraw::*;
 use reset::*;
-use set_admin::*;
+use set_automation_limits::*;
+use set_config::*;
+use set_fee_collector::*;
+use set_fee_rate::*;
+use set_pause::*;
+use set_payout::*;
+use transfer_stake::*;
 use withdraw::*;
+use withdraw_liquid::*;
 use wrap::*;
 
 use ore_api::instruction::*;
@@ -54,31 +106,57 @@ pub fn process_instruction(
     match ix {
         // Miner
         OreInstruction::Automate => process_automate(accounts, data)?,
+        OreInstruction::SetAutomationLimits => process_set_automation_limits(accounts, data)?,
         OreInstruction::Checkpoint => process_checkpoint(accounts, data)?,
+        OreInstruction::CheckpointMany => process_checkpoint_many(accounts, data)?,
         OreInstruction::ClaimSOL => process_claim_sol(accounts, data)?,
         OreInstruction::ClaimORE => process_claim_ore(accounts, data)?,
         OreInstruction::Deploy => process_deploy(accounts, data)?,
+        OreInstruction::DeployWeighted => process_deploy_weighted(accounts, data)?,
+        OreInstruction::DeployWide => process_deploy_wide(accounts, data)?,
         OreInstruction::Log => process_log(accounts, data)?,
         OreInstruction::Close => process_close(accounts, data)?,
         OreInstruction::Reset => process_reset(accounts, data)?,
         OreInstruction::ReloadSOL => process_reload_sol(accounts, data)?,
+        OreInstruction::Abort => process_abort(accounts, data)?,
+        OreInstruction::CloseMiner => process_close_miner(accounts, data)?,
+        OreInstruction::SetPayout => process_set_payout(accounts, data)?,
+        OreInstruction::Harvest => process_harvest(accounts, data)?,
+        OreInstruction::MigrateMiner => process_migrate_miner(accounts, data)?,
+        OreInstruction::MigrateRound => process_migrate_round(accounts, data)?,
 
         // Staker
         OreInstruction::Deposit => process_deposit(accounts, data)?,
         OreInstruction::Withdraw => process_withdraw(accounts, data)?,
         OreInstruction::ClaimYield => process_claim_yield(accounts, data)?,
         OreInstruction::CompoundYield => process_compound_yield(accounts, data)?,
+        OreInstruction::LockStake => process_lock_stake(accounts, data)?,
+        OreInstruction::RequestWithdraw => process_request_withdraw(accounts, data)?,
+        OreInstruction::CompleteWithdraw => process_complete_withdraw(accounts, data)?,
+        OreInstruction::TransferStake => process_transfer_stake(accounts, data)?,
+        OreInstruction::DepositLiquid => process_deposit_liquid(accounts, data)?,
+        OreInstruction::WithdrawLiquid => process_withdraw_liquid(accounts, data)?,
+        OreInstruction::ExpireLock => process_expire_lock(accounts, data)?,
 
         // Admin
+        OreInstruction::Initialize => process_initialize(accounts, data)?,
         OreInstruction::Buyback => process_buyback(accounts, data)?,
         OreInstruction::Bury => process_bury(accounts, data)?,
         OreInstruction::Wrap => process_wrap(accounts, data)?,
-        OreInstruction::SetAdmin => process_set_admin(accounts, data)?,
+        OreInstruction::ProposeAdmin => process_propose_admin(accounts, data)?,
+        OreInstruction::AcceptAdmin => process_accept_admin(accounts, data)?,
+        OreInstruction::CancelAdminProposal => process_cancel_admin_proposal(accounts, data)?,
+        OreInstruction::SetConfig => process_set_config(accounts, data)?,
+        OreInstruction::SetFeeCollector => process_set_fee_collector(accounts, data)?,
+        OreInstruction::SetFeeRate => process_set_fee_rate(accounts, data)?,
+        OreInstruction::SetPause => process_set_pause(accounts, data)?,
         OreInstruction::NewVar => process_new_var(accounts, data)?,
         OreInstruction::Liq => process_liq(accounts, data)?,
         OreInstruction::InitializeLpPool => {
             process_initialize_lp_pool(program_id, accounts, data)?
         }
+        OreInstruction::InitializeReceipt => process_initialize_receipt(accounts, data)?,
+        OreInstruction::MigrateConfig => process_migrate_config(accounts, data)?,
     }
 
     Ok(())
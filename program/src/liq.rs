@@ -18,6 +18,9 @@ pub fn process_liq(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult
     board_info.as_account_mut::<Board>(&ore_api::ID)?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_TREASURY) {
+        return Err(OreError::Paused.into());
+    }
     board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
     manager_info.has_address(&LIQ_MANAGER)?;
     manager_sol_info
@@ -36,7 +39,9 @@ pub fn process_liq(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult
     let treasury_sol =
         treasury_sol_info.as_associated_token_account(treasury_info.key, &SOL_MINT)?;
     let liq_amount = treasury_sol.amount();
-    assert!(liq_amount > 0);
+    if liq_amount == 0 {
+        return Err(OreError::AmountTooSmall.into());
+    }
 
     // Build swap accounts.
     transfer_signed(
@@ -51,7 +56,9 @@ pub fn process_liq(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult
     // Record post-swap balances.
     let treasury_sol =
         treasury_sol_info.as_associated_token_account(treasury_info.key, &SOL_MINT)?;
-    assert_eq!(treasury_sol.amount(), 0);
+    if treasury_sol.amount() != 0 {
+        return Err(OreError::TransferIncomplete.into());
+    }
     sol_log(&format!("💦 Sent {} SOL to liq manager", lamports_to_sol(liq_amount)).as_str());
 
     // Emit event.
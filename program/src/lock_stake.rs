@@ -0,0 +1,63 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Locks staked ORE for a fixed duration in exchange for a boosted reward weight.
+pub fn process_lock_stake(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = LockStake::try_from_bytes(data)?;
+    let duration = i64::from_le_bytes(args.duration);
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, stake_info, treasury_info, board_info, ore_program] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_STAKING) {
+        return Err(OreError::Paused.into());
+    }
+    stake_info.has_seeds(
+        &[STAKE, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+        &ore_api::ID,
+    )?;
+    let stake = stake_info
+        .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut(|s| s.authority == *signer_info.key)?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Lock stake.
+    stake.lock(duration, &clock, treasury)?;
+
+    sol_log(
+        &format!(
+            "Locked stake until {} ({} bps)",
+            stake.unlock_at, stake.lock_multiplier
+        )
+        .as_str(),
+    );
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        StakeEvent {
+            disc: OreEvent::Stake as u64,
+            authority: stake.authority,
+            kind: StakeKind::Lock as u64,
+            amount: stake.balance,
+            balance: stake.balance,
+            total_staked: treasury.total_staked,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Reallocates a config account created before the current layout and fills in default values.
+pub fn process_migrate_config(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    config_info.is_writable()?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Exit early if config is already up to date.
+    let size = 8 + std::mem::size_of::<Config>();
+    let old_size = config_info.data_len();
+    if old_size >= size {
+        return Ok(());
+    }
+
+    // Realloc config account.
+    config_info.realloc(size, true)?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+
+    // Set defaults for new fields.
+    config.fee_collector = ADMIN_FEE_COLLECTOR;
+    config.fee_rate = ADMIN_FEE;
+    config.pending_admin = Pubkey::default();
+    config.paused = 0;
+    config.round_slots = ROUND_SLOTS;
+    config.intermission_slots = INTERMISSION_SLOTS;
+    config.expiry_slots = EXPIRY_SLOTS;
+    config.squares = DEFAULT_SQUARES;
+    config.abort_slots = ABORT_SLOTS;
+    config.randomness = RANDOMNESS_ENTROPY;
+    config.withdraw_cooldown = 0;
+    config.reserved = [0; 8];
+
+    // Pay for additional rent.
+    let rent = Rent::get()?;
+    let upgrade_rent = rent.minimum_balance(size) - rent.minimum_balance(old_size);
+    config_info.collect(upgrade_rent, signer_info)?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Reallocates a legacy miner account to the current layout.
+pub fn process_migrate_miner(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, miner_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    miner_info.is_writable()?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Exit early if miner is already up to date.
+    let size = 8 + std::mem::size_of::<Miner>();
+    let old_size = miner_info.data_len();
+    if old_size >= size {
+        return Ok(());
+    }
+
+    // Assert miner is on the legacy layout.
+    let discriminator = miner_info
+        .try_borrow_data()?
+        .first()
+        .copied()
+        .ok_or(ProgramError::UninitializedAccount)?;
+    if discriminator != LegacyMiner::discriminator() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Copy legacy miner before the layout changes.
+    let legacy = *miner_info.as_account::<LegacyMiner>(&ore_api::ID)?;
+    miner_info.has_seeds(
+        &[MINER, &config.mint.to_bytes(), &legacy.authority.to_bytes()],
+        &ore_api::ID,
+    )?;
+
+    // Realloc and rewrite miner account.
+    miner_info.realloc(size, true)?;
+    miner_info.try_borrow_mut_data()?[0] = Miner::discriminator();
+    let miner = miner_info.as_account_mut::<Miner>(&ore_api::ID)?;
+    *miner = legacy.migrate();
+
+    // Pay for additional rent.
+    let rent = Rent::get()?;
+    let upgrade_rent = rent.minimum_balance(size) - rent.minimum_balance(old_size);
+    miner_info.collect(upgrade_rent, signer_info)?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Reallocates a legacy round account to the current layout.
+pub fn process_migrate_round(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, round_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    round_info.is_writable()?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Exit early if round is already up to date.
+    let size = 8 + std::mem::size_of::<Round>();
+    let old_size = round_info.data_len();
+    if old_size >= size {
+        return Ok(());
+    }
+
+    // Assert round is on the legacy layout.
+    let discriminator = round_info
+        .try_borrow_data()?
+        .first()
+        .copied()
+        .ok_or(ProgramError::UninitializedAccount)?;
+    if discriminator != LegacyRound::discriminator() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Copy legacy round before the layout changes.
+    let legacy = *round_info.as_account::<LegacyRound>(&ore_api::ID)?;
+    round_info.has_seeds(
+        &[ROUND, &config.mint.to_bytes(), &legacy.id.to_le_bytes()],
+        &ore_api::ID,
+    )?;
+
+    // Realloc and rewrite round account.
+    round_info.realloc(size, true)?;
+    round_info.try_borrow_mut_data()?[0] = Round::discriminator();
+    let round = round_info.as_account_mut::<Round>(&ore_api::ID)?;
+    *round = legacy.migrate();
+
+    // Pay for additional rent.
+    let rent = Rent::get()?;
+    let upgrade_rent = rent.minimum_balance(size) - rent.minimum_balance(old_size);
+    round_info.collect(upgrade_rent, signer_info)?;
+
+    Ok(())
+}
@@ -1,10 +1,10 @@
 use ore_api::prelude::*;
 use steel::*;
 
-/// Sets the admin.
-pub fn process_set_admin(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+/// Proposes a new admin. The proposal takes effect once accepted by the new admin.
+pub fn process_propose_admin(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
-    let args = SetAdmin::try_from_bytes(data)?;
+    let args = ProposeAdmin::try_from_bytes(data)?;
     let new_admin = Pubkey::new_from_array(args.admin);
 
     // Load accounts.
@@ -20,8 +20,8 @@ pub fn process_set_admin(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRe
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
     system_program.is_program(&system_program::ID)?;
 
-    // Set admin.
-    config.admin = new_admin;
+    // Record pending admin.
+    config.pending_admin = new_admin;
 
     Ok(())
 }
@@ -2,20 +2,33 @@ use ore_api::prelude::*;
 use solana_program::{log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
+use crate::upgrade_automation;
+
 /// Claims a block reward.
 pub fn process_reload_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, config_info, automation_info, miner_info, system_program] = accounts else {
+    let [signer_info, config_info, automation_info, miner_info, system_program, board_info, ore_program] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_MINING) {
+        return Err(OreError::Paused.into());
+    }
+    let upgrade_rent = upgrade_automation(automation_info)?;
     let automation = automation_info
         .as_account_mut::<Automation>(&ore_api::ID)?
         .assert_mut(|a| a.executor == *signer_info.key || a.executor == EXECUTOR_ADDRESS)?
-        .assert_mut(|a| a.reload > 0)?;
+        .assert_mut(|a| a.reload > 0)?
+        .assert_mut_err(
+            |a| a.balance >= upgrade_rent,
+            OreError::InsufficientAutomationBalance.into(),
+        )?;
+    automation.balance -= upgrade_rent;
     automation_info.has_seeds(
         &[
             AUTOMATION,
@@ -36,12 +49,15 @@ pub fn process_reload_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
         &ore_api::ID,
     )?;
     system_program.is_program(&system_program::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
 
     // Claim sol from the miner.
     let amount = miner.claim_sol(&clock);
 
     // Increment automation balance.
     automation.balance += amount;
+    automation.lifetime_reloaded += amount;
 
     // Transfer SOL to automation.
     miner_info.send(amount, automation_info);
@@ -49,5 +65,19 @@ pub fn process_reload_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     // Log
     sol_log(&format!("Reloading {} SOL", lamports_to_sol(amount)).as_str());
 
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        ReloadEvent {
+            disc: OreEvent::Reload as u64,
+            authority: automation.authority,
+            amount,
+            automation_balance: automation.balance,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
     Ok(())
 }
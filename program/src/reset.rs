@@ -3,8 +3,6 @@ use ore_api::prelude::*;
 use solana_program::{keccak, log::sol_log};
 use steel::*;
 
-// TODO Integrate admin fee
-
 /// Pays out the winners and block reward.
 pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
@@ -12,7 +10,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     let (ore_accounts, other_accounts) = accounts.split_at(14);
     sol_log(&format!("Ore accounts: {:?}", ore_accounts.len()).to_string());
     sol_log(&format!("Other accounts: {:?}", other_accounts.len()).to_string());
-    let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, _top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
+    let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
         ore_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -20,13 +18,19 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     signer_info.is_signer()?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_MINING) {
+        return Err(OreError::Paused.into());
+    }
     board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
     let board = board_info
         .as_account_mut::<Board>(&ore_api::ID)?
-        .assert_mut(|b| clock.slot >= b.end_slot + INTERMISSION_SLOTS)?;
+        .assert_mut_err(
+            |b| clock.slot >= b.end_slot.saturating_add(config.intermission_slots),
+            OreError::RoundNotEnded.into(),
+        )?;
     fee_collector_info
         .is_writable()?
-        .has_address(&ADMIN_FEE_COLLECTOR)?;
+        .has_address(&config.fee_collector)?;
     let round = round_info
         .as_account_mut::<Round>(&ore_api::ID)?
         .assert_mut(|r| r.id == board.round_id)?;
@@ -61,9 +65,9 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     )?;
     let round_next = round_next_info.as_account_mut::<Round>(&ore_api::ID)?;
     round_next.id = board.round_id + 1;
-    round_next.deployed = [0; 25];
+    round_next.deployed = [0; MAX_SQUARES];
     round_next.slot_hash = [0; 32];
-    round_next.count = [0; 25];
+    round_next.count = [0; MAX_SQUARES];
     round_next.expires_at = u64::MAX; // Set to max, to indicate round is waiting for first deploy to begin.
     round_next.rent_payer = *signer_info.key;
     round_next.motherlode = 0;
@@ -72,6 +76,9 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     round_next.total_deployed = 0;
     round_next.total_vaulted = 0;
     round_next.total_winnings = 0;
+    round_next.squares = config.squares;
+    round_next.fee_rate = 0;
+    round_next.randomness = config.randomness;
 
     // Sample random variable
     let (entropy_accounts, mint_accounts) = other_accounts.split_at(2);
@@ -80,25 +87,37 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     let [var_info, entropy_program] = entropy_accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    let var = var_info
-        .has_address(&VAR_ADDRESS)?
-        .as_account::<Var>(&entropy_api::ID)?
-        .assert(|v| v.authority == *board_info.key)?
-        .assert(|v| v.slot_hash != [0; 32])?
-        .assert(|v| v.seed != [0; 32])?
-        .assert(|v| v.value != [0; 32])?;
-    entropy_program.is_program(&entropy_api::ID)?;
-
-    // Print the seed and slot hash.
-    let seed = keccak::Hash::new_from_array(var.seed);
-    let slot_hash = keccak::Hash::new_from_array(var.slot_hash);
-    sol_log(&format!("var slothash: {:?}", slot_hash).to_string());
-    sol_log(&format!("var seed: {:?}", seed).to_string());
-
-    // Read the finalized value from the var.
-    let value = keccak::Hash::new_from_array(var.value);
-    sol_log(&format!("var value: {:?}", value).to_string());
-    round.slot_hash = var.value;
+    match round.randomness {
+        RANDOMNESS_ENTROPY => {
+            let var = var_info
+                .has_address(&VAR_ADDRESS)?
+                .as_account::<Var>(&entropy_api::ID)?
+                .assert(|v| v.authority == *board_info.key)?
+                .assert(|v| v.slot_hash != [0; 32])?
+                .assert(|v| v.seed != [0; 32])?
+                .assert(|v| v.value != [0; 32])?;
+            entropy_program.is_program(&entropy_api::ID)?;
+
+            // Print the seed and slot hash.
+            let seed = keccak::Hash::new_from_array(var.seed);
+            let slot_hash = keccak::Hash::new_from_array(var.slot_hash);
+            sol_log(&format!("var slothash: {:?}", slot_hash).to_string());
+            sol_log(&format!("var seed: {:?}", seed).to_string());
+
+            // Read the finalized value from the var.
+            let value = keccak::Hash::new_from_array(var.value);
+            sol_log(&format!("var value: {:?}", value).to_string());
+            round.slot_hash = var.value;
+        }
+        RANDOMNESS_SLOT_HASHES => {
+            // Read the hash of the first available slot at or after the end of the round. If it has
+            // already been evicted from the sysvar, the round is refunded.
+            round.slot_hash = get_slot_hash(board.end_slot, slot_hashes_sysvar)?;
+            let slot_hash = keccak::Hash::new_from_array(round.slot_hash);
+            sol_log(&format!("slot hash: {:?}", slot_hash).to_string());
+        }
+        _ => return Err(OreError::InvalidRandomness.into()),
+    }
 
     // Exit early if no slot hash was found.
     let Some(r) = round.rng() else {
@@ -124,6 +143,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
                 total_vaulted: round.total_vaulted,
                 total_winnings: round.total_winnings,
                 total_minted: 0,
+                randomness: round.randomness,
                 ts: clock.unix_timestamp,
             }
             .to_bytes(),
@@ -136,8 +156,11 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         return Ok(());
     };
 
+    // Snapshot the fee rate so checkpoints charge the same rate as the reset.
+    round.fee_rate = config.fee_rate;
+
     // Caculate admin fees.
-    let total_admin_fee = round.total_deployed / 100;
+    let total_admin_fee = round.admin_fee(round.total_deployed);
 
     // Get the winning square.
     let winning_square = round.winning_square(r);
@@ -165,6 +188,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
                 total_vaulted: round.total_vaulted,
                 total_winnings: round.total_winnings,
                 total_minted: 0,
+                randomness: round.randomness,
                 ts: clock.unix_timestamp,
             }
             .to_bytes(),
@@ -183,7 +207,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
 
     // Get winnings amount (total deployed on all non-winning squares, minus admin fee).
     let winnings = round.calculate_total_winnings(winning_square);
-    let winnings_admin_fee = winnings / 100; // 1% admin fee.
+    let winnings_admin_fee = round.admin_fee(winnings);
     let winnings = winnings - winnings_admin_fee;
 
     // Subtract vault amount from winnings.
@@ -194,13 +218,14 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     treasury.balance += vault_amount;
 
     // Sanity check.
-    assert!(
-        round.total_deployed
-            >= round.total_vaulted
-                + round.total_winnings
-                + round.deployed[winning_square]
-                + winnings_admin_fee
-    );
+    if round.total_deployed
+        < round.total_vaulted
+            + round.total_winnings
+            + round.deployed[winning_square]
+            + winnings_admin_fee
+    {
+        return Err(OreError::InvalidRoundTotals.into());
+    }
 
     // Calculate mint amounts.
     let mut mint_supply = mint.supply();
@@ -249,17 +274,25 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         &[TREASURY, &config.mint.to_bytes()],
     )?;
 
-    // Validate top miner.
-    // TODO Safety checks here (if no one won).
-    // let mut top_miner_address = Pubkey::default();
-    // let top_miner_sample = round.top_miner_sample(r, winning_square);
-    // let top_miner = top_miner_info
-    //     .as_account::<Miner>(&ore_api::ID)?
-    //     .assert(|m| m.round_id == round.id)?
-    //     .assert(|m| {
-    //         m.cumulative[winning_square] >= top_miner_sample
-    //             && top_miner_sample < m.cumulative[winning_square] + m.deployed[winning_square]
-    //     })?;
+    // Validate top miner, if provided.
+    if round.top_miner != SPLIT_ADDRESS && !top_miner_info.data_is_empty() {
+        let top_miner_sample = round.top_miner_sample(r, winning_square);
+        let top_miner = top_miner_info
+            .as_account::<Miner>(&ore_api::ID)?
+            .assert_err(
+                |m| round.is_top_miner(m, winning_square, top_miner_sample),
+                OreError::InvalidTopMiner.into(),
+            )?;
+        top_miner_info.has_seeds(
+            &[
+                MINER,
+                &config.mint.to_bytes(),
+                &top_miner.authority.to_bytes(),
+            ],
+            &ore_api::ID,
+        )?;
+        round.top_miner = top_miner.authority;
+    }
 
     // Emit event.
     program_log(
@@ -278,6 +311,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
             total_vaulted: round.total_vaulted,
             total_winnings: round.total_winnings,
             total_minted: total_mint_amount,
+            randomness: round.randomness,
             ts: clock.unix_timestamp,
         }
         .to_bytes(),
@@ -286,7 +320,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     // Reset board.
     board.round_id += 1;
     board.start_slot = clock.slot + 1;
-    board.end_slot = u64::MAX; // board.start_slot + 150;
+    board.end_slot = u64::MAX;
 
     // Do SOL transfers.
     round_info.send(total_admin_fee, &fee_collector_info);
@@ -294,3 +328,79 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
 
     Ok(())
 }
+
+/// Returns the hash of the first slot at or after the given slot from the SlotHashes sysvar, or
+/// `[u8::MAX; 32]` if no such slot is available. The given slot itself may have been skipped.
+///
+/// The `[u8::MAX; 32]` sentinel is returned when the slot has already rotated out of SlotHashes.
+/// `Round::rng` treats it as missing randomness, so the round is fully refunded.
+fn get_slot_hash(
+    slot: u64,
+    slot_hashes_sysvar: &AccountInfo<'_>,
+) -> Result<[u8; 32], ProgramError> {
+    let data = slot_hashes_sysvar.try_borrow_data()?;
+    let Some(len) = data.get(0..8) else {
+        return Err(ProgramError::InvalidAccountData);
+    };
+    let len = u64::from_le_bytes(len.try_into().unwrap()) as usize;
+    let mut slot_hash = [u8::MAX; 32];
+    for i in 0..len {
+        // Each entry is a (slot, hash) pair, sorted by slot descending. The last entry at or after
+        // the given slot is the first available one.
+        let offset = 8 + i * 40;
+        let Some(entry) = data.get(offset..offset + 40) else {
+            break;
+        };
+        let entry_slot = u64::from_le_bytes(entry[0..8].try_into().unwrap());
+        if entry_slot < slot {
+            break;
+        }
+        slot_hash = entry[8..40].try_into().unwrap();
+    }
+    Ok(slot_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot_hashes_data(entries: &[(u64, [u8; 32])]) -> Vec<u8> {
+        let mut data = (entries.len() as u64).to_le_bytes().to_vec();
+        for (slot, hash) in entries {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(hash);
+        }
+        data
+    }
+
+    fn slot_hash(slot: u64, data: &mut [u8]) -> Result<[u8; 32], ProgramError> {
+        let key = Pubkey::default();
+        let mut lamports = 0;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, data, &key, false, 0);
+        get_slot_hash(slot, &info)
+    }
+
+    #[test]
+    fn test_get_slot_hash() {
+        // Entries are sorted by slot descending, and slot 103 was skipped.
+        let mut data = slot_hashes_data(&[(105, [5; 32]), (104, [4; 32]), (102, [2; 32])]);
+        assert_eq!(slot_hash(104, &mut data), Ok([4; 32]));
+        assert_eq!(slot_hash(103, &mut data), Ok([4; 32]));
+        assert_eq!(slot_hash(102, &mut data), Ok([2; 32]));
+        assert_eq!(slot_hash(1, &mut data), Ok([2; 32]));
+        assert_eq!(slot_hash(106, &mut data), Ok([u8::MAX; 32]));
+    }
+
+    #[test]
+    fn test_get_slot_hash_invalid_data() {
+        assert_eq!(
+            slot_hash(100, &mut []),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // A truncated sysvar only yields the complete entries.
+        let mut data = slot_hashes_data(&[(105, [5; 32]), (104, [4; 32])]);
+        data.truncate(data.len() - 1);
+        assert_eq!(slot_hash(100, &mut data), Ok([5; 32]));
+    }
+}
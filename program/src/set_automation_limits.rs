@@ -0,0 +1,70 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::upgrade_automation;
+
+/// Sets the limits after which an automation stops deploying and closes.
+pub fn process_set_automation_limits(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetAutomationLimits::try_from_bytes(data)?;
+    let max_rounds = u64::from_le_bytes(args.max_rounds);
+    let max_spend = u64::from_le_bytes(args.max_spend);
+    let expires_at = u64::from_le_bytes(args.expires_at);
+    let stop_on_win = u64::from_le_bytes(args.stop_on_win) > 0;
+
+    // Load accounts.
+    let [signer_info, config_info, automation_info, miner_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    automation_info.is_writable()?.has_seeds(
+        &[
+            AUTOMATION,
+            &config.mint.to_bytes(),
+            &signer_info.key.to_bytes(),
+        ],
+        &ore_api::ID,
+    )?;
+    miner_info.has_seeds(
+        &[MINER, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+        &ore_api::ID,
+    )?;
+    let miner = miner_info
+        .as_account::<Miner>(&ore_api::ID)?
+        .assert(|m| m.authority == *signer_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Upgrade automation account.
+    let upgrade_rent = upgrade_automation(automation_info)?;
+    if upgrade_rent > 0 {
+        automation_info.collect(upgrade_rent, signer_info)?;
+    }
+    let automation = automation_info
+        .as_account_mut::<Automation>(&ore_api::ID)?
+        .assert_mut_err(
+            |a| a.authority == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+
+    // Set limits and reset counters.
+    automation.max_rounds = max_rounds;
+    automation.rounds_played = 0;
+    automation.max_spend = max_spend;
+    automation.total_spent = 0;
+    automation.expires_at = expires_at;
+    automation.stop_on_win = stop_on_win as u64;
+    automation.last_wins = miner.lifetime_wins;
+
+    sol_log(
+        &format!(
+            "Automation limits: {} rounds, {} lamports, expires at slot {}, stop on win: {}",
+            max_rounds, max_spend, expires_at, stop_on_win
+        )
+        .as_str(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Updates the emission parameters in the config.
+pub fn process_set_config(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetConfig::try_from_bytes(data)?;
+    let reward_per_round = u64::from_le_bytes(args.reward_per_round);
+    let max_supply = u64::from_le_bytes(args.max_supply);
+    let motherlode_bps = u64::from_le_bytes(args.motherlode_bps);
+    let stake_bps = u64::from_le_bytes(args.stake_bps);
+    let round_slots = u64::from_le_bytes(args.round_slots);
+    let intermission_slots = u64::from_le_bytes(args.intermission_slots);
+    let expiry_slots = u64::from_le_bytes(args.expiry_slots);
+    let abort_slots = u64::from_le_bytes(args.abort_slots);
+    let randomness = u64::from_le_bytes(args.randomness);
+    let withdraw_cooldown = i64::from_le_bytes(args.withdraw_cooldown);
+    if motherlode_bps > DENOMINATOR_BPS || stake_bps > DENOMINATOR_BPS {
+        return Err(OreError::InvalidBasisPoints.into());
+    }
+    if round_slots == 0
+        || expiry_slots == 0
+        || abort_slots <= intermission_slots
+        || abort_slots >= expiry_slots
+    {
+        return Err(OreError::InvalidRoundTiming.into());
+    }
+
+    // Slot hash randomness can be ground by the slot leader and is only meant for test clusters.
+    if randomness != RANDOMNESS_ENTROPY && randomness != RANDOMNESS_SLOT_HASHES {
+        return Err(OreError::InvalidRandomness.into());
+    }
+    if withdraw_cooldown < 0 {
+        return Err(OreError::InvalidWithdrawCooldown.into());
+    }
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, board_info, config_info, mint_info, ore_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account_mut::<Config>(&ore_api::ID)?;
+    config.assert_mut_err(
+        |c| c.admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    board_info.as_account::<Board>(&ore_api::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    let mint = mint_info.has_address(&config.mint)?.as_mint()?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Max supply cannot be set below what has already been minted.
+    if max_supply < mint.supply() {
+        return Err(OreError::MaxSupplyBelowSupply.into());
+    }
+
+    // Update config.
+    config.reward_per_round = reward_per_round;
+    config.max_supply = max_supply;
+    config.motherlode_bps = motherlode_bps;
+    config.stake_bps = stake_bps;
+    config.round_slots = round_slots;
+    config.intermission_slots = intermission_slots;
+    config.expiry_slots = expiry_slots;
+    config.abort_slots = abort_slots;
+    config.randomness = randomness;
+    config.withdraw_cooldown = withdraw_cooldown;
+
+    // Rounds must stay open past the intermission long enough for the bot fee window.
+    if expiry_slots <= intermission_slots.saturating_add(config.bot_fee_slots()) {
+        return Err(OreError::InvalidRoundTiming.into());
+    }
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        ConfigEvent::new(config, clock.unix_timestamp).to_bytes(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the admin fee rate charged on deployed SOL.
+pub fn process_set_fee_rate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetFeeRate::try_from_bytes(data)?;
+    let fee_rate = u64::from_le_bytes(args.fee_rate);
+    if fee_rate > DENOMINATOR_BPS {
+        return Err(OreError::InvalidBasisPoints.into());
+    }
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, board_info, config_info, ore_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account_mut::<Config>(&ore_api::ID)?;
+    config.assert_mut_err(
+        |c| c.admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    board_info.as_account::<Board>(&ore_api::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Set fee rate.
+    config.fee_rate = fee_rate;
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        ConfigEvent::new(config, clock.unix_timestamp).to_bytes(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets which instruction groups are paused. SetPayout, SetAutomationLimits and ExpireLock are
+/// never paused: they move no funds, and only redirect future claims, update automation limits
+/// or drop an elapsed lock boost.
+pub fn process_set_pause(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetPause::try_from_bytes(data)?;
+    let paused = u64::from_le_bytes(args.paused);
+    if paused & !PAUSE_ALL != 0 {
+        return Err(OreError::InvalidPauseFlags.into());
+    }
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, board_info, config_info, ore_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account_mut::<Config>(&ore_api::ID)?;
+    config.assert_mut_err(
+        |c| c.admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    board_info.as_account::<Board>(&ore_api::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Set pause flags.
+    config.paused = paused;
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        ConfigEvent::new(config, clock.unix_timestamp).to_bytes(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Sets the address that receives a miner's claimed rewards.
+pub fn process_set_payout(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetPayout::try_from_bytes(data)?;
+    let payout = Pubkey::new_from_array(args.payout);
+
+    // Load accounts.
+    let [signer_info, config_info, miner_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    miner_info.has_seeds(
+        &[MINER, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+        &ore_api::ID,
+    )?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&ore_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?;
+
+    // Set payout address.
+    miner.payout = payout;
+
+    sol_log(&format!("Payout address: {}", miner.payout_address()).as_str());
+
+    Ok(())
+}
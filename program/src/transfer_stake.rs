@@ -0,0 +1,158 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+/// Transfers a stake position to a new authority, merging it into their stake account if one
+/// exists. Merging into an existing account requires the new authority's signature.
+pub fn process_transfer_stake(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, new_authority_info, config_info, mint_info, stake_info, stake_tokens_info, new_stake_info, new_stake_tokens_info, treasury_info, system_program, token_program, associated_token_program, board_info, ore_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    if new_authority_info.key == signer_info.key {
+        return Err(OreError::InvalidStakeTransfer.into());
+    }
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_STAKING | PAUSE_WITHDRAWALS) {
+        return Err(OreError::Paused.into());
+    }
+    mint_info.has_address(&config.mint)?.as_mint()?;
+
+    // Program accounts, such as the liquid staking receipt mint, cannot sign for a stake position.
+    if *new_authority_info.key == receipt_pda(config.mint).0
+        || *new_authority_info.owner == ore_api::ID
+    {
+        return Err(OreError::InvalidStakeTransfer.into());
+    }
+    stake_info.has_seeds(
+        &[STAKE, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+        &ore_api::ID,
+    )?;
+    let stake = stake_info
+        .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut(|s| s.authority == *signer_info.key)?
+        .assert_mut_err(
+            |s| s.pending_withdraw == 0,
+            OreError::PendingWithdraw.into(),
+        )?;
+    stake_tokens_info
+        .is_writable()?
+        .as_associated_token_account(stake_info.key, mint_info.key)?;
+    new_stake_info.is_writable()?.has_seeds(
+        &[
+            STAKE,
+            &config.mint.to_bytes(),
+            &new_authority_info.key.to_bytes(),
+        ],
+        &ore_api::ID,
+    )?;
+    new_stake_tokens_info.is_writable()?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+    board_info.has_seeds(&[BOARD, &config.mint.to_bytes()], &ore_api::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Open new stake account.
+    let new_stake = if new_stake_info.data_is_empty() {
+        create_program_account::<Stake>(
+            new_stake_info,
+            system_program,
+            signer_info,
+            &ore_api::ID,
+            &[
+                STAKE,
+                &config.mint.to_bytes(),
+                &new_authority_info.key.to_bytes(),
+            ],
+        )?;
+        let new_stake = new_stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
+        new_stake.init(*new_authority_info.key, treasury);
+        new_stake
+    } else {
+        // Merging changes the lock on the destination, so its authority must consent.
+        new_authority_info.is_signer()?;
+        new_stake_info
+            .as_account_mut::<Stake>(&ore_api::ID)?
+            .assert_mut(|s| s.authority == *new_authority_info.key)?
+    };
+
+    // Create new stake tokens account.
+    if new_stake_tokens_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            new_stake_info,
+            new_stake_tokens_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        new_stake_tokens_info.as_associated_token_account(new_stake_info.key, mint_info.key)?;
+    }
+
+    // Move the stake position.
+    let compound_fee_reserve = stake.compound_fee_reserve;
+    let amount = stake.transfer(new_stake, &clock, treasury)?;
+
+    // Transfer ORE to the new stake account.
+    transfer_signed(
+        stake_info,
+        stake_tokens_info,
+        new_stake_tokens_info,
+        token_program,
+        amount,
+        &[STAKE, &config.mint.to_bytes(), &signer_info.key.to_bytes()],
+    )?;
+
+    // Move the compound fee reserve.
+    stake_info.send(compound_fee_reserve, new_stake_info);
+
+    // Log transfer.
+    sol_log(
+        &format!(
+            "Transferring {} ORE to {}",
+            amount_to_ui_amount(amount, TOKEN_DECIMALS),
+            new_authority_info.key
+        )
+        .as_str(),
+    );
+
+    // Safety check.
+    let stake_tokens =
+        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    let new_stake_tokens =
+        new_stake_tokens_info.as_associated_token_account(new_stake_info.key, mint_info.key)?;
+    if stake_tokens.amount() < stake.balance + stake.pending_withdraw
+        || new_stake_tokens.amount() < new_stake.balance + new_stake.pending_withdraw
+    {
+        return Err(OreError::StakeBalanceMismatch.into());
+    }
+
+    // Emit event.
+    program_log(
+        config.mint,
+        &[board_info.clone(), ore_program.clone()],
+        StakeEvent {
+            disc: OreEvent::Stake as u64,
+            authority: new_stake.authority,
+            kind: StakeKind::Transfer as u64,
+            amount,
+            balance: new_stake.balance,
+            total_staked: treasury.total_staked,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
@@ -16,6 +16,9 @@ pub fn process_wrap(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
     signer_info.is_signer()?.has_address(&BURY_AUTHORITY)?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     config_info.has_seeds(&[CONFIG, &config.mint.to_bytes()], &ore_api::ID)?;
+    if config.is_paused(PAUSE_TREASURY) {
+        return Err(OreError::Paused.into());
+    }
     let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
     treasury_info.has_seeds(&[TREASURY, &config.mint.to_bytes()], &ore_api::ID)?;
     treasury_sol_info
@@ -31,10 +34,9 @@ pub fn process_wrap(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
 
     // Check min balance.
     let min_balance = Rent::get()?.minimum_balance(std::mem::size_of::<Treasury>());
-    assert!(
-        treasury_info.lamports() >= min_balance,
-        "Insufficient SOL balance"
-    );
+    if treasury_info.lamports() < min_balance {
+        return Err(OreError::InsufficientTreasuryBalance.into());
+    }
 
     // Update treasury.
     treasury.balance -= amount;